@@ -0,0 +1,81 @@
+//! Times the parse step and both parts of every day that has an input.
+//!
+//! Criterion keeps its results under `target/criterion` and reports changes
+//! against the previous run. To compare against a fixed point instead:
+//!
+//! ```text
+//! cargo bench -p aoc -- --save-baseline before
+//! cargo bench -p aoc -- --baseline before
+//! ```
+//!
+//! Pass a filter such as `day16/` to only run some of the days.
+//!
+//! Set `AOC_BENCH_SIZE` to time random inputs of that size instead of the
+//! puzzle inputs, such as to see how a day scales:
+//!
+//! ```text
+//! AOC_BENCH_SIZE=1000 cargo bench -p aoc -- day9/
+//! ```
+
+use std::{env, time::Duration};
+
+use aoc::days::DAYS;
+use common::{
+    input::{InputConfig, InputError},
+    Params,
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Environment variable with the size of random inputs to use instead of the
+/// puzzle inputs
+const SIZE_ENV: &str = "AOC_BENCH_SIZE";
+
+fn bench_days(c: &mut Criterion) {
+    let config = InputConfig::from_env();
+    let size = env::var(SIZE_ENV).ok().map(|s| {
+        s.parse::<usize>()
+            .unwrap_or_else(|e| panic!("{SIZE_ENV}: {e}"))
+    });
+    for day in &DAYS {
+        let mut params = Params::from_env().unwrap_or_else(|e| panic!("{e}"));
+        let input = match size {
+            Some(size) => {
+                let generated = day.generate(0, size);
+                params.merge(&generated.params);
+                generated.input
+            }
+            None => match config.load(day.day, day.embedded_input) {
+                Ok(input) => input,
+                Err(InputError::NotFound { .. }) => {
+                    eprintln!("Skipping day {}: no input", day.day);
+                    continue;
+                }
+                Err(e) => panic!("{e}"),
+            },
+        };
+
+        let parsed = match day.parse(&input, &params) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("Skipping day {}: {e}", day.day);
+                continue;
+            }
+        };
+
+        let mut group = c.benchmark_group(format!("day{}", day.day));
+        // Some parts take seconds, so keep the sample count low
+        group
+            .sample_size(10)
+            .measurement_time(Duration::from_secs(3));
+        group.bench_function("parse", |b| {
+            b.iter_with_large_drop(|| day.parse(&input, &params))
+        });
+        for part in [1, 2] {
+            group.bench_function(format!("part{part}"), |b| b.iter(|| parsed.part(part)));
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,57 @@
+//! Checks the real inputs against the answers manifest, which lives in
+//! `common` so the example tests can read answer files too
+
+pub use common::answers::*;
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        input::{InputConfig, InputError},
+        Params,
+    };
+
+    use super::*;
+    use crate::days::DAYS;
+
+    /// Runs every day with a local input against the manifest
+    #[test]
+    fn test_answers() {
+        let answers = Answers::load().unwrap();
+        let config = InputConfig::from_env();
+        let mut failures = Vec::new();
+        for day in &DAYS {
+            let parts = [1, 2]
+                .into_iter()
+                .filter(|&p| answers.get(day.day, p).is_some())
+                .collect::<Vec<_>>();
+            if parts.is_empty() {
+                continue;
+            }
+            let input = match config.load(day.day, day.embedded_input) {
+                Ok(input) => input,
+                Err(InputError::NotFound { .. }) => {
+                    eprintln!("Skipping day {}: no input", day.day);
+                    continue;
+                }
+                Err(e) => panic!("{e}"),
+            };
+            let run = match day.run(&input, &Params::default(), &parts) {
+                Ok(run) => run,
+                Err(e) => {
+                    failures.push(e.to_string());
+                    continue;
+                }
+            };
+            for p in run.parts {
+                let expected = answers.get(day.day, p.part).unwrap();
+                if p.answer != expected {
+                    failures.push(format!(
+                        "Day {} part {}: expected {expected}, got {}",
+                        day.day, p.part, p.answer
+                    ));
+                }
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,268 @@
+//! Runs one day against every input in a directory, such as the inputs of
+//! everyone on a team. The expected answers for `NAME.txt` go beside it in
+//! `NAME.answers`, in the same `<day> <part> <answer>` format as `answers.txt`.
+//! Inputs without them are still run, but only checked for errors. Any
+//! parameters an input needs go in `NAME.params`, so a day's `examples`
+//! directory is a batch too.
+
+use std::{
+    ffi::OsStr,
+    fmt::Display,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use common::{answers::Answers, Day, Params};
+
+/// Extension of the expected answer files
+pub const ANSWERS_EXTENSION: &str = "answers";
+/// Extension of the parameter files
+pub const PARAMS_EXTENSION: &str = "params";
+
+/// An input in the batch, and where its expected answers would be
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Case {
+    pub name: String,
+    pub input: PathBuf,
+    pub answers: PathBuf,
+    pub params: PathBuf,
+}
+
+/// Every input in `dir`, by name. Answer and parameter files, hidden files
+/// and directories are skipped.
+pub fn cases(dir: &Path) -> Result<Vec<Case>, String> {
+    let read_error = |e| format!("Could not read {}: {e}", dir.display());
+    let mut cases = Vec::new();
+    for entry in fs::read_dir(dir).map_err(read_error)? {
+        let input = entry.map_err(read_error)?.path();
+        let name = input
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        if !input.is_file()
+            || name.starts_with('.')
+            || [ANSWERS_EXTENSION, PARAMS_EXTENSION]
+                .iter()
+                .any(|ext| input.extension() == Some(OsStr::new(ext)))
+        {
+            continue;
+        }
+        cases.push(Case {
+            name,
+            answers: input.with_extension(ANSWERS_EXTENSION),
+            params: input.with_extension(PARAMS_EXTENSION),
+            input,
+        });
+    }
+    cases.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(cases)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// There's no expected answer to compare with
+    Unchecked,
+    /// The input couldn't be read or solved
+    Error(String),
+}
+
+/// The result of one part on one input
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub input: String,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub verdict: Verdict,
+    pub elapsed: Duration,
+}
+
+/// Runs `parts` of `day` on one input, with any parameters it has taking the
+/// place of those in `params`. A panic in the solution is reported as
+/// an error for that input rather than ending the batch.
+pub fn run_case(day: &Day, case: &Case, parts: &[u8], params: &Params) -> Vec<Outcome> {
+    // The full error goes to stderr, with a short reason in the table
+    let failed = |e: &dyn Display, reason: &str| {
+        eprintln!("error: {}: {e}", case.name);
+        parts
+            .iter()
+            .map(|&part| Outcome {
+                input: case.name.clone(),
+                part,
+                answer: None,
+                verdict: Verdict::Error(reason.to_owned()),
+                elapsed: Duration::ZERO,
+            })
+            .collect()
+    };
+    let expected = match Answers::read(&case.answers) {
+        Ok(expected) => expected,
+        Err(e) => return failed(&e, "invalid answers"),
+    };
+    let params = match Params::read(&case.params) {
+        Ok(own) => {
+            let mut params = params.clone();
+            params.merge(&own);
+            params
+        }
+        Err(e) => return failed(&e, "invalid params"),
+    };
+    let input = match fs::read_to_string(&case.input) {
+        Ok(input) => input,
+        Err(e) => {
+            let e = format!("Could not read {}: {e}", case.input.display());
+            return failed(&e, "no input");
+        }
+    };
+    let run = match panic::catch_unwind(AssertUnwindSafe(|| day.run(&input, &params, parts))) {
+        Ok(Ok(run)) => run,
+        Ok(Err(e)) => return failed(&e, "invalid input"),
+        Err(_) => return failed(&"The solution panicked", "panicked"),
+    };
+    run.parts
+        .into_iter()
+        .map(|p| {
+            let verdict = match expected.get(day.day, p.part) {
+                None => Verdict::Unchecked,
+                Some(expected) if expected == p.answer => Verdict::Pass,
+                Some(expected) => Verdict::Fail {
+                    expected: expected.to_owned(),
+                },
+            };
+            Outcome {
+                input: case.name.clone(),
+                part: p.part,
+                answer: Some(p.answer),
+                verdict,
+                elapsed: p.elapsed,
+            }
+        })
+        .collect()
+}
+
+/// Runs `parts` of `day` on every input in `dir`
+pub fn run(day: &Day, dir: &Path, parts: &[u8], params: &Params) -> Result<Vec<Outcome>, String> {
+    let cases = cases(dir)?;
+    if cases.is_empty() {
+        return Err(format!("No inputs in {}", dir.display()));
+    }
+    Ok(cases
+        .iter()
+        .flat_map(|case| run_case(day, case, parts, params))
+        .collect())
+}
+
+/// Prints a row per outcome and a count of each verdict. Returns whether
+/// every outcome passed or was unchecked.
+pub fn print(outcomes: &[Outcome]) -> bool {
+    let cells = outcomes
+        .iter()
+        .map(|o| {
+            let (expected, result) = match &o.verdict {
+                Verdict::Pass => (o.answer.clone().unwrap_or_default(), "pass".to_owned()),
+                Verdict::Fail { expected } => (expected.clone(), "FAIL".to_owned()),
+                Verdict::Unchecked => (String::new(), "unchecked".to_owned()),
+                Verdict::Error(e) => (String::new(), format!("error: {e}")),
+            };
+            [
+                o.input.clone(),
+                o.part.to_string(),
+                o.answer.clone().unwrap_or_default(),
+                expected,
+                crate::format_duration(o.elapsed),
+                result,
+            ]
+        })
+        .collect::<Vec<_>>();
+    let headers = ["Input", "Part", "Answer", "Expected", "Time", "Result"];
+    let widths = headers.map(|h| h.len());
+    let widths = cells.iter().fold(widths, |mut widths, row| {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.chars().count());
+        }
+        widths
+    });
+    let print_row = |row: [&str; 6]| {
+        let [input, part, answer, expected, time, result] = row;
+        println!(
+            "{input:<w0$}  {part:>w1$}  {answer:<w2$}  {expected:<w3$}  {time:>w4$}  {result}",
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+            w4 = widths[4],
+        );
+    };
+    print_row(headers);
+    for row in &cells {
+        print_row(row.each_ref().map(String::as_str));
+    }
+
+    let count = |f: fn(&Verdict) -> bool| outcomes.iter().filter(|o| f(&o.verdict)).count();
+    let failed = count(|v| matches!(v, Verdict::Fail { .. }));
+    let errors = count(|v| matches!(v, Verdict::Error(_)));
+    println!(
+        "{} passed, {failed} failed, {} unchecked, {errors} errors",
+        count(|v| *v == Verdict::Pass),
+        count(|v| *v == Verdict::Unchecked),
+    );
+    failed == 0 && errors == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    #[test]
+    fn test_run() {
+        let dir = env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("alice.txt"), EXAMPLE).unwrap();
+        fs::write(dir.join("alice.answers"), "1 1 11\n1 2 31\n").unwrap();
+        fs::write(dir.join("alice.params"), "unused=1\n").unwrap();
+        fs::write(dir.join("bob.txt"), EXAMPLE).unwrap();
+        fs::write(dir.join("bob.answers"), "1 1 12\n").unwrap();
+        fs::write(dir.join("carol"), "3 x\n").unwrap();
+        fs::write(dir.join(".hidden"), "").unwrap();
+
+        let day = aoc::days::get(1).unwrap();
+        let cases = cases(&dir).unwrap();
+        let names = cases.iter().map(|c| c.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(vec!["alice.txt", "bob.txt", "carol"], names);
+        assert_eq!(dir.join("carol.answers"), cases[2].answers);
+
+        let outcomes = run(day, &dir, &[1, 2], &Params::default()).unwrap();
+        let verdicts = outcomes
+            .iter()
+            .map(|o| (o.input.as_str(), o.part, &o.verdict))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ("alice.txt", 1, &Verdict::Pass),
+                ("alice.txt", 2, &Verdict::Pass),
+                (
+                    "bob.txt",
+                    1,
+                    &Verdict::Fail {
+                        expected: "12".to_owned()
+                    }
+                ),
+                ("bob.txt", 2, &Verdict::Unchecked),
+            ],
+            verdicts[..4]
+        );
+        assert!(outcomes[4..]
+            .iter()
+            .all(|o| o.input == "carol" && matches!(o.verdict, Verdict::Error(_))));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,51 @@
+use common::Day;
+
+pub static DAYS: [Day; 25] = [
+    Day::new::<day1::Day1>(day1::EMBEDDED_INPUT),
+    Day::new::<day2::Day2>(day2::EMBEDDED_INPUT),
+    Day::new::<day3::Day3>(day3::EMBEDDED_INPUT),
+    Day::new::<day4::Day4>(day4::EMBEDDED_INPUT),
+    Day::new::<day5::Day5>(day5::EMBEDDED_INPUT),
+    Day::new::<day6::Day6>(day6::EMBEDDED_INPUT),
+    Day::new::<day7::Day7>(day7::EMBEDDED_INPUT),
+    Day::new::<day8::Day8>(day8::EMBEDDED_INPUT),
+    Day::new::<day9::Day9>(day9::EMBEDDED_INPUT),
+    Day::new::<day10::Day10>(day10::EMBEDDED_INPUT),
+    Day::new::<day11::Day11>(day11::EMBEDDED_INPUT),
+    Day::new::<day12::Day12>(day12::EMBEDDED_INPUT),
+    Day::new::<day13::Day13>(day13::EMBEDDED_INPUT),
+    Day::new::<day14::Day14>(day14::EMBEDDED_INPUT),
+    Day::new::<day15::Day15>(day15::EMBEDDED_INPUT),
+    Day::new::<day16::Day16>(day16::EMBEDDED_INPUT),
+    Day::new::<day17::Day17>(day17::EMBEDDED_INPUT),
+    Day::new::<day18::Day18>(day18::EMBEDDED_INPUT),
+    Day::new::<day19::Day19>(day19::EMBEDDED_INPUT),
+    Day::new::<day20::Day20>(day20::EMBEDDED_INPUT),
+    Day::new::<day21::Day21>(day21::EMBEDDED_INPUT),
+    Day::new::<day22::Day22>(day22::EMBEDDED_INPUT),
+    Day::new::<day23::Day23>(day23::EMBEDDED_INPUT),
+    Day::new::<day24::Day24>(day24::EMBEDDED_INPUT),
+    Day::new::<day25::Day25>(day25::EMBEDDED_INPUT),
+];
+
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated() {
+        for day in &DAYS {
+            for (seed, size) in [(0, 0), (1, 1), (2, 6), (3, 25)] {
+                let generated = day.generate(seed, size);
+                let run = day.run(&generated.input, &generated.params, &[1, 2]);
+                if let Err(e) = run {
+                    panic!("{e} (seed {seed}, size {size})\n{}", generated.input);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Entry points for the fuzz targets in `fuzz/`. The `parse` target checks
+//! that no input makes a day's parse step panic. The `solve` target also runs
+//! both parts on anything that parses, to find input the parse step should
+//! have rejected, though parts may still panic on puzzles with no answer.
+//!
+//! ```text
+//! cargo +nightly fuzz run parse
+//! AOC_FUZZ_DAY=13 cargo +nightly fuzz run solve -- -timeout=5
+//! ```
+//!
+//! `aoc generate` makes good seeds for a day's corpus. Inputs that crashed
+//! are kept in `fuzz/regressions` as `dayN-<what>.txt`, and the tests here
+//! check they no longer do.
+
+use std::{env, sync::OnceLock};
+
+use common::{Day, Params};
+
+use crate::days::{self, DAYS};
+
+/// Environment variable choosing a single day to fuzz
+pub const DAY_ENV: &str = "AOC_FUZZ_DAY";
+
+/// The day in `AOC_FUZZ_DAY` and all of `data`, or else the day picked by the
+/// first byte of `data` and the rest of it
+pub fn pick(data: &[u8]) -> Option<(&'static Day, &[u8])> {
+    static DAY: OnceLock<Option<&Day>> = OnceLock::new();
+    let day = DAY.get_or_init(|| {
+        let day = env::var(DAY_ENV).ok()?;
+        let day = day.parse().ok().and_then(days::get);
+        Some(day.unwrap_or_else(|| panic!("{DAY_ENV} must be a solved day")))
+    });
+    match (day, data) {
+        (Some(day), _) => Some((day, data)),
+        (None, [first, rest @ ..]) => Some((&DAYS[*first as usize % DAYS.len()], rest)),
+        (None, []) => None,
+    }
+}
+
+/// Parses `data` as input for `day`, which may fail but mustn't panic
+pub fn parse(day: &Day, data: &[u8]) {
+    // The runner only reads UTF-8 input
+    if let Ok(input) = std::str::from_utf8(data) {
+        _ = day.parse(input, &Params::default());
+    }
+}
+
+/// Parses `data` as input for `day` and runs both parts if it is accepted
+pub fn solve(day: &Day, data: &[u8]) {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(parsed) = day.parse(input, &Params::default()) {
+            parsed.part(1);
+            parsed.part(2);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        panic::{self, AssertUnwindSafe},
+    };
+
+    use common::input::workspace_dir;
+
+    use super::*;
+
+    #[test]
+    fn test_regressions() {
+        let dir = workspace_dir().join("fuzz").join("regressions");
+        let mut failed = Vec::new();
+        for entry in fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            let day = name
+                .strip_prefix("day")
+                .and_then(|n| n.split_once('-'))
+                .and_then(|(n, _)| n.parse().ok())
+                .and_then(days::get)
+                .unwrap_or_else(|| panic!("{name} should be named `dayN-<what>.txt`"));
+            let data = fs::read(&path).unwrap();
+            if panic::catch_unwind(AssertUnwindSafe(|| solve(day, &data))).is_err() {
+                failed.push(name);
+            }
+        }
+        assert!(failed.is_empty(), "Inputs still crash: {failed:?}");
+    }
+}
@@ -0,0 +1,6 @@
+//! Every day's solution in one place, shared by the `aoc` runner, the answer
+//! tests, the benchmarks and the fuzz targets
+
+pub mod answers;
+pub mod days;
+pub mod fuzz;
@@ -0,0 +1,774 @@
+use std::{
+    collections::BTreeSet,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use aoc::days;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use common::{
+    answers::Answers,
+    input::{workspace_dir, InputConfig},
+    logging,
+    memory::{self, CountingAlloc, Usage},
+    params::PARAMS_ENV,
+    render::Recorder,
+    Day, Params,
+};
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::render::{Export, ImageFormat};
+
+mod batch;
+mod render;
+mod scaffold;
+
+/// Counts each part's allocations for the Peak and Allocs columns
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+/// Runs Advent of Code 2024 solutions and reports their answers and timings
+#[derive(Debug, Parser)]
+#[command(args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Days to run: a single day (`5`), an inclusive range (`3-9`) or `all`
+    #[arg(value_parser = parse_days, default_value = "all")]
+    days: Vec<RangeInclusive<u8>>,
+
+    /// Only run one part of each day
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file for the selected day, or `-` to read stdin
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Directory containing `dayN.txt` or `dayN/input.txt` files. Defaults to
+    /// `$AOC_INPUTS`, then the workspace
+    #[arg(long)]
+    inputs: Option<PathBuf>,
+
+    /// Compare answers against the expected answers in `answers.txt`
+    #[arg(long, conflicts_with = "record")]
+    check: bool,
+
+    /// Save the answers to `answers.txt`, replacing any already recorded
+    #[arg(long)]
+    record: bool,
+
+    /// How to print the results
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    /// Log more of each day's working to stderr: `-v` for info, `-vv` for
+    /// debug and `-vvv` for trace
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Set a puzzle parameter for the selected days, e.g. `--set size=6`.
+    /// Adds to `$AOC_PARAMS`. `aoc params` lists them
+    #[arg(long = "set", value_name = "NAME=VALUE", value_parser = parse_param)]
+    set: Vec<(String, String)>,
+
+    /// Log levels for particular days, e.g. `14=debug,21=trace`. Adds to
+    /// `$AOC_LOG`
+    #[arg(long, value_name = "FILTERS")]
+    log: Option<String>,
+
+    /// Run the days, and both parts of each day, concurrently on this many
+    /// threads, given as `-j=N`. `-j` alone uses every core, so `-j 5` runs
+    /// day 5 on every core. Results are still printed in order
+    #[arg(
+        short,
+        long,
+        value_name = "N",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "0"
+    )]
+    jobs: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// An aligned table with a total time
+    Table,
+    /// One JSON object per line for each day and part
+    Json,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Create `dayN` from `template/` and register it with the workspace
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// List each day's puzzle parameters and their defaults
+    Params,
+    /// Print a random input for a day, such as for stress testing
+    Generate {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Side of the grid or number of lines. Real inputs are usually 100
+        /// to 1000
+        #[arg(short, long, default_value_t = 100)]
+        size: usize,
+        /// Seed for the input. Defaults to a random one, which is printed to
+        /// stderr
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Run a day against every input in a directory, checking each
+    /// `NAME.txt` against the answers in `NAME.answers` if there are any
+    Batch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Directory of inputs and expected answers
+        dir: PathBuf,
+        /// Only run one part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Set a puzzle parameter, e.g. `--set size=6`. Adds to `$AOC_PARAMS`
+        #[arg(long = "set", value_name = "NAME=VALUE", value_parser = parse_param)]
+        set: Vec<(String, String)>,
+    },
+    /// Record a day's simulation and save it as images or an animated GIF
+    Render(RenderArgs),
+}
+
+#[derive(Debug, Args)]
+struct RenderArgs {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Part to record
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Input file, or `-` to read stdin. Defaults to the day's usual input
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+    /// The GIF to write, or the directory for PNG and PPM images. Defaults to
+    /// `dayN-partP.gif` or `dayN-partP/`
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    #[arg(long, value_enum, default_value_t = ImageFormat::Gif)]
+    format: ImageFormat,
+    /// Pixels along each side of a cell
+    #[arg(long, default_value_t = 4)]
+    scale: usize,
+    /// Keep one of every N frames
+    #[arg(long, value_name = "N", default_value_t = 1)]
+    every: usize,
+    /// Most frames to keep. The last is always the final state
+    #[arg(long, value_name = "N", default_value_t = 500)]
+    frames: usize,
+    /// Hundredths of a second to show each GIF frame for
+    #[arg(long, default_value_t = 5)]
+    delay: u16,
+    /// Set a puzzle parameter, e.g. `--set size=6`. Adds to `$AOC_PARAMS`
+    #[arg(long = "set", value_name = "NAME=VALUE", value_parser = parse_param)]
+    set: Vec<(String, String)>,
+}
+
+/// The parts to run when `--part` is `part`
+fn parts(part: Option<u8>) -> &'static [u8] {
+    match part {
+        Some(1) => &[1],
+        Some(2) => &[2],
+        _ => &[1, 2],
+    }
+}
+
+fn parse_param(s: &str) -> Result<(String, String), String> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("Expected `name=value`, found `{s}`"))?;
+    Ok((name.trim().to_owned(), value.trim().to_owned()))
+}
+
+fn parse_days(s: &str) -> Result<RangeInclusive<u8>, String> {
+    let (first, last) = days::DAYS
+        .first()
+        .zip(days::DAYS.last())
+        .map(|(f, l)| (f.day, l.day))
+        .expect("At least one day is registered");
+    if s == "all" {
+        return Ok(first..=last);
+    }
+    let parse = |n: &str| {
+        n.trim()
+            .parse::<u8>()
+            .map_err(|e| format!("Invalid day `{n}`: {e}"))
+    };
+    let range = match s.split_once(['-', ':']).or_else(|| s.split_once("..=")) {
+        Some((a, b)) => parse(a)?..=parse(b)?,
+        None => parse(s)?..=parse(s)?,
+    };
+    if range.is_empty() || *range.start() < first || *range.end() > last {
+        return Err(format!("Days must be between {first} and {last}"));
+    }
+    Ok(range)
+}
+
+struct Row {
+    day: u8,
+    part: u8,
+    answer: Result<String, String>,
+    /// Parse time, reported on the first row of each day
+    parse: Option<Duration>,
+    elapsed: Duration,
+    memory: Usage,
+    /// Where the input came from, if one was found
+    input: Option<String>,
+}
+
+/// The rows for one day, and the full error if it couldn't be run
+struct DayRun {
+    rows: Vec<Row>,
+    error: Option<String>,
+}
+
+fn run(day: &Day, parts: &[u8], config: &InputConfig, params: &Params, concurrent: bool) -> DayRun {
+    let source = config.locate(day.day, day.embedded_input);
+    let input_id = source.as_ref().ok().map(ToString::to_string);
+    // The full error goes to stderr, with a short reason in the table
+    let failed = |e: &dyn std::error::Error, reason: &str| DayRun {
+        rows: parts
+            .iter()
+            .map(|&part| Row {
+                day: day.day,
+                part,
+                answer: Err(reason.to_owned()),
+                parse: None,
+                elapsed: Duration::ZERO,
+                memory: Usage::default(),
+                input: input_id.clone(),
+            })
+            .collect(),
+        error: Some(e.to_string()),
+    };
+    let input = match source.and_then(|s| s.read()) {
+        Ok(input) => input,
+        Err(e) => return failed(&e, "no input"),
+    };
+    let run = day.run_with(&input, params, |parsed| {
+        if concurrent {
+            parts.par_iter().map(|&part| parsed.timed(part)).collect()
+        } else {
+            parts.iter().map(|&part| parsed.timed(part)).collect()
+        }
+    });
+    let run = match run {
+        Ok(run) => run,
+        Err(e) => return failed(&e, "invalid input"),
+    };
+    let rows = run
+        .parts
+        .into_iter()
+        .enumerate()
+        .map(|(i, p)| Row {
+            day: day.day,
+            part: p.part,
+            answer: Ok(p.answer),
+            parse: (i == 0).then_some(run.parse),
+            elapsed: p.elapsed,
+            memory: p.memory,
+            input: input_id.clone(),
+        })
+        .collect();
+    DayRun { rows, error: None }
+}
+
+/// Runs each of `days` in order, or on a pool of `jobs` threads (0 for one per
+/// core). Either way the results come back in the order of `days`.
+fn run_all(
+    days: &[&Day],
+    parts: &[u8],
+    config: &InputConfig,
+    params: &Params,
+    jobs: Option<usize>,
+) -> Result<Vec<DayRun>, String> {
+    let Some(jobs) = jobs else {
+        return Ok(days
+            .iter()
+            .map(|day| run(day, parts, config, params, false))
+            .collect());
+    };
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .map_err(|e| format!("Could not start {jobs} threads: {e}"))?;
+    Ok(pool.install(|| {
+        days.par_iter()
+            .map(|day| run(day, parts, config, params, true))
+            .collect()
+    }))
+}
+
+/// Turns answers that differ from the manifest into errors
+fn check(rows: &mut [Row], answers: &Answers) {
+    for row in rows {
+        let Ok(answer) = &row.answer else {
+            continue;
+        };
+        match answers.get(row.day, row.part) {
+            Some(expected) if expected != answer => {
+                row.answer = Err(format!("{answer} (expected {expected})"))
+            }
+            _ => (),
+        }
+    }
+}
+
+fn record(rows: &[Row]) -> Result<(), String> {
+    let mut answers = Answers::load()?;
+    for row in rows {
+        if let Ok(answer) = &row.answer {
+            answers.insert(row.day, row.part, answer.clone());
+        }
+    }
+    answers
+        .save()
+        .map_err(|e| format!("Could not write {}: {e}", Answers::path().display()))
+}
+
+fn format_duration(d: Duration) -> String {
+    if d.as_secs() > 0 {
+        format!("{:.2}s", d.as_secs_f64())
+    } else if d.as_millis() > 0 {
+        format!("{:.2}ms", d.as_secs_f64() * 1_000.0)
+    } else {
+        format!("{:.2}µs", d.as_secs_f64() * 1_000_000.0)
+    }
+}
+
+/// Prints the results, with the total of the individual times and the wall
+/// clock time of the whole run, which is less when run concurrently. Peak is
+/// the most heap memory each part held at once, and Allocs how many times it
+/// allocated.
+fn print_table(rows: &[Row], wall: Duration) {
+    let answers = rows
+        .iter()
+        .map(|r| match &r.answer {
+            Ok(a) => a.clone(),
+            Err(e) => format!("error: {e}"),
+        })
+        .collect::<Vec<_>>();
+    let parses = rows
+        .iter()
+        .map(|r| r.parse.map(format_duration).unwrap_or_default())
+        .collect::<Vec<_>>();
+    let times = rows
+        .iter()
+        .map(|r| format_duration(r.elapsed))
+        .collect::<Vec<_>>();
+    let peaks = rows
+        .iter()
+        .map(|r| memory::format_bytes(r.memory.peak))
+        .collect::<Vec<_>>();
+    let allocs = rows
+        .iter()
+        .map(|r| r.memory.allocations.to_string())
+        .collect::<Vec<_>>();
+    let width = |col: &[String], header: &str| {
+        col.iter()
+            .map(|c| c.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(header.len())
+    };
+    let answer_width = width(&answers, "Answer");
+    let parse_width = width(&parses, "Parse");
+    let time_width = width(&times, "Time");
+    let peak_width = width(&peaks, "Peak");
+    let alloc_width = width(&allocs, "Allocs");
+
+    println!(
+        "Day  Part  {:<answer_width$}  {:>parse_width$}  {:>time_width$}  {:>peak_width$}  \
+         {:>alloc_width$}",
+        "Answer", "Parse", "Time", "Peak", "Allocs"
+    );
+    let columns = answers.into_iter().zip(parses).zip(times).zip(peaks);
+    for ((((answer, parse), time), peak), (row, allocs)) in columns.zip(rows.iter().zip(allocs)) {
+        println!(
+            "{:>3}  {:>4}  {answer:<answer_width$}  {parse:>parse_width$}  {time:>time_width$}  \
+             {peak:>peak_width$}  {allocs:>alloc_width$}",
+            row.day, row.part
+        );
+    }
+    let total = rows
+        .iter()
+        .map(|r| r.parse.unwrap_or_default() + r.elapsed)
+        .sum();
+    println!(
+        "{:>total_width$}  {:>time_width$}",
+        "Total",
+        format_duration(total),
+        total_width = "Day  Part  ".len() + answer_width + 2 + parse_width
+    );
+    println!(
+        "{:>total_width$}  {:>time_width$}",
+        "Wall",
+        format_duration(wall),
+        total_width = "Day  Part  ".len() + answer_width + 2 + parse_width
+    );
+}
+
+fn print_params() {
+    for day in days::DAYS.iter().filter(|d| !d.params.is_empty()) {
+        println!("Day {}", day.day);
+        let width = day
+            .params
+            .iter()
+            .map(|p| p.name.len() + p.default.len())
+            .max();
+        for p in day.params {
+            let setting = format!("{}={}", p.name, p.default);
+            println!(
+                "  {setting:<width$}  {}",
+                p.help,
+                width = width.unwrap_or(0) + 1
+            );
+        }
+    }
+}
+
+/// The solution for `day`, or exits if there isn't one yet
+fn solved(day: u8) -> &'static Day {
+    days::get(day).unwrap_or_else(|| {
+        eprintln!("error: Day {day} is not solved yet");
+        std::process::exit(2);
+    })
+}
+
+/// `$AOC_PARAMS` with the values in `set`, or exits if `day` doesn't have one
+/// of them
+fn day_params(day: &Day, set: Vec<(String, String)>) -> Params {
+    let mut params = Params::from_env().unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        std::process::exit(2);
+    });
+    for (name, value) in set {
+        if !day.params.iter().any(|p| p.name == name) {
+            eprintln!("error: Day {} has no `{name}` parameter", day.day);
+            std::process::exit(2);
+        }
+        params.insert(name, value);
+    }
+    params
+}
+
+fn generate(day: u8, size: usize, seed: Option<u64>) {
+    let day = solved(day);
+    let seed = seed.unwrap_or_else(|| {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+        eprintln!("Seed {seed}");
+        seed
+    });
+    let generated = day.generate(seed, size);
+    print!("{}", generated.input);
+    if generated.params.names().next().is_some() {
+        eprintln!(
+            "Run with {PARAMS_ENV}={} to match the input",
+            generated.params
+        );
+    }
+}
+
+fn batch(day: u8, dir: &Path, parts: &[u8], set: Vec<(String, String)>) {
+    let day = solved(day);
+    let params = day_params(day, set);
+    let outcomes = batch::run(day, dir, parts, &params).unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    });
+    if !batch::print(&outcomes) {
+        std::process::exit(1);
+    }
+}
+
+fn render(args: RenderArgs) {
+    let day = solved(args.day);
+    let params = day_params(day, args.set);
+    let config = InputConfig {
+        path: args.input,
+        ..InputConfig::from_env()
+    };
+    let fail = |e: &dyn std::fmt::Display| -> ! {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    };
+    let input = config
+        .load(day.day, day.embedded_input)
+        .unwrap_or_else(|e| fail(&e));
+    let parsed = day.parse(&input, &params).unwrap_or_else(|e| fail(&e));
+    let mut recorder = Recorder::new(args.every, args.frames);
+    parsed.animate(args.part, &mut recorder);
+    if recorder.frames().is_empty() {
+        fail(&format!(
+            "Day {} part {} has nothing to render",
+            day.day, args.part
+        ));
+    }
+
+    let export = Export {
+        format: args.format,
+        scale: args.scale,
+        delay: args.delay,
+    };
+    let output = args.output.unwrap_or_else(|| {
+        let name = format!("day{}-part{}", day.day, args.part);
+        match args.format {
+            ImageFormat::Gif => PathBuf::from(name).with_extension("gif"),
+            _ => PathBuf::from(name),
+        }
+    });
+    if let Err(e) = export.write(recorder.frames(), &output) {
+        fail(&e);
+    }
+    eprintln!(
+        "Wrote {} frames to {}",
+        recorder.frames().len(),
+        output.display()
+    );
+}
+
+/// A result as reported by `--format json`. Times are in nanoseconds and
+/// memory in bytes.
+#[derive(Debug, Serialize)]
+struct JsonRow<'a> {
+    day: u8,
+    part: u8,
+    answer: Option<&'a str>,
+    error: Option<&'a str>,
+    parse_ns: Option<u128>,
+    elapsed_ns: u128,
+    peak_bytes: u64,
+    allocated_bytes: u64,
+    allocations: u64,
+    input: Option<&'a str>,
+}
+
+impl<'a> From<&'a Row> for JsonRow<'a> {
+    fn from(row: &'a Row) -> Self {
+        Self {
+            day: row.day,
+            part: row.part,
+            answer: row.answer.as_deref().ok(),
+            error: row.answer.as_ref().err().map(String::as_str),
+            parse_ns: row.parse.map(|d| d.as_nanos()),
+            elapsed_ns: row.elapsed.as_nanos(),
+            peak_bytes: row.memory.peak,
+            allocated_bytes: row.memory.allocated,
+            allocations: row.memory.allocations,
+            input: row.input.as_deref(),
+        }
+    }
+}
+
+fn print_json(rows: &[Row]) {
+    for row in rows {
+        let json = serde_json::to_string(&JsonRow::from(row)).expect("Rows serialize to JSON");
+        println!("{json}");
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    logging::init(cli.verbose, cli.log.as_deref());
+    match cli.command {
+        Some(Command::New { day }) => {
+            if let Err(e) = scaffold::new_day(&workspace_dir(), day) {
+                eprintln!("error: {e}");
+                std::process::exit(1);
+            }
+            println!(
+                "Created day{day}. Add the puzzle input to day{day}/input.txt and the example \
+                 to day{day}/examples"
+            );
+            return;
+        }
+        Some(Command::Params) => {
+            print_params();
+            return;
+        }
+        Some(Command::Generate { day, size, seed }) => {
+            generate(day, size, seed);
+            return;
+        }
+        Some(Command::Batch {
+            day,
+            dir,
+            part,
+            set,
+        }) => {
+            batch(day, &dir, parts(part), set);
+            return;
+        }
+        Some(Command::Render(args)) => {
+            render(args);
+            return;
+        }
+        None => (),
+    }
+    let selected: BTreeSet<u8> = cli.days.into_iter().flatten().collect();
+    let parts = parts(cli.part);
+
+    if cli.input.is_some() && selected.len() != 1 {
+        eprintln!("error: --input can only be used when running a single day");
+        std::process::exit(2);
+    }
+    let mut params = Params::from_env().unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        std::process::exit(2);
+    });
+    for (name, value) in cli.set {
+        let known = selected
+            .iter()
+            .filter_map(|&d| days::get(d))
+            .any(|day| day.params.iter().any(|p| p.name == name));
+        if !known {
+            eprintln!("error: None of the selected days have a `{name}` parameter");
+            std::process::exit(2);
+        }
+        params.insert(name, value);
+    }
+    let mut config = InputConfig::from_env();
+    config.path = cli.input;
+    config.dir = cli.inputs.or(config.dir);
+
+    let days = selected
+        .into_iter()
+        .filter_map(days::get)
+        .collect::<Vec<_>>();
+    let start = Instant::now();
+    let runs = run_all(&days, parts, &config, &params, cli.jobs).unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    });
+    let wall = start.elapsed();
+    let mut rows = Vec::new();
+    for run in runs {
+        if let Some(e) = run.error {
+            eprintln!("error: {e}");
+        }
+        rows.extend(run.rows);
+    }
+    if cli.check {
+        match Answers::load() {
+            Ok(answers) => check(&mut rows, &answers),
+            Err(e) => {
+                eprintln!("error: {e}");
+                std::process::exit(1);
+            }
+        }
+    }
+    match cli.format {
+        Format::Table => print_table(&rows, wall),
+        Format::Json => print_json(&rows),
+    }
+    if cli.record {
+        if let Err(e) = record(&rows) {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        }
+        eprintln!("Recorded answers in {}", Answers::path().display());
+    }
+
+    if rows.iter().any(|r| r.answer.is_err()) {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jobs() {
+        let parse = |args: &[&str]| {
+            let cli = Cli::try_parse_from([&["aoc"], args].concat()).unwrap();
+            (cli.jobs, cli.days)
+        };
+        assert_eq!((None, vec![1..=25]), parse(&[]));
+        assert_eq!((Some(0), vec![5..=5]), parse(&["-j", "5"]));
+        assert_eq!((Some(0), vec![5..=5]), parse(&["5", "--jobs"]));
+        assert_eq!((Some(4), vec![5..=5]), parse(&["-j=4", "5"]));
+        assert_eq!((Some(4), vec![1..=25]), parse(&["--jobs=4"]));
+    }
+
+    #[test]
+    fn test_run_all() {
+        let dir = std::env::temp_dir().join(format!("aoc-run-all-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let days = days::DAYS
+            .iter()
+            .filter(|day| day.params.is_empty())
+            .collect::<Vec<_>>();
+        for day in &days {
+            let input = day.generate(u64::from(day.day), 20).input;
+            std::fs::write(dir.join(format!("day{}.txt", day.day)), input).unwrap();
+        }
+        // An input that can't be parsed still gets its rows, in the right place
+        std::fs::write(dir.join("day5.txt"), "garbage").unwrap();
+        let config = InputConfig {
+            path: None,
+            dir: Some(dir.clone()),
+        };
+        let params = Params::default();
+        let answers = |runs: Vec<DayRun>| {
+            runs.into_iter()
+                .flat_map(|run| run.rows)
+                .map(|row| (row.day, row.part, row.answer))
+                .collect::<Vec<_>>()
+        };
+
+        let sequential = answers(run_all(&days, &[1, 2], &config, &params, None).unwrap());
+        let concurrent = answers(run_all(&days, &[1, 2], &config, &params, Some(4)).unwrap());
+        assert_eq!(2 * days.len(), sequential.len());
+        assert_eq!(sequential, concurrent);
+        assert!(concurrent
+            .iter()
+            .any(|(day, _, answer)| *day == 5 && answer.is_err()));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_json_row() {
+        let row = Row {
+            day: 17,
+            part: 1,
+            answer: Ok("4,6,3,5".to_owned()),
+            parse: Some(Duration::from_micros(3)),
+            elapsed: Duration::from_nanos(250),
+            memory: Usage {
+                allocations: 3,
+                allocated: 96,
+                peak: 64,
+            },
+            input: Some("day17/input.txt".to_owned()),
+        };
+        assert_eq!(
+            r#"{"day":17,"part":1,"answer":"4,6,3,5","error":null,"parse_ns":3000,"elapsed_ns":250,"peak_bytes":64,"allocated_bytes":96,"allocations":3,"input":"day17/input.txt"}"#,
+            serde_json::to_string(&JsonRow::from(&row)).unwrap()
+        );
+
+        let row = Row {
+            answer: Err("no input".to_owned()),
+            parse: None,
+            input: None,
+            ..row
+        };
+        assert_eq!(
+            r#"{"day":17,"part":1,"answer":null,"error":"no input","parse_ns":null,"elapsed_ns":250,"peak_bytes":64,"allocated_bytes":96,"allocations":3,"input":null}"#,
+            serde_json::to_string(&JsonRow::from(&row)).unwrap()
+        );
+    }
+}
@@ -0,0 +1,222 @@
+//! Saves the frames a day records as a numbered PPM or PNG image per frame, or
+//! as one animated GIF
+
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use clap::ValueEnum;
+use common::render::{Frame, Rgb};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ImageFormat {
+    /// One animated GIF
+    Gif,
+    /// A directory of PNG images
+    Png,
+    /// A directory of binary PPM images
+    Ppm,
+}
+
+impl ImageFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Gif => "gif",
+            ImageFormat::Png => "png",
+            ImageFormat::Ppm => "ppm",
+        }
+    }
+}
+
+/// How to write the frames
+#[derive(Debug, Clone, Copy)]
+pub struct Export {
+    pub format: ImageFormat,
+    /// Pixels along each side of a cell
+    pub scale: usize,
+    /// Hundredths of a second each GIF frame is shown for
+    pub delay: u16,
+}
+
+impl Export {
+    /// Writes `frames` to the file or directory at `path`, returning the files
+    /// written
+    pub fn write(&self, frames: &[Frame], path: &Path) -> Result<Vec<PathBuf>, String> {
+        let first = frames.first().ok_or("There are no frames to write")?;
+        if frames
+            .iter()
+            .any(|f| (f.width(), f.height()) != (first.width(), first.height()))
+        {
+            return Err("Every frame must be the same size".to_owned());
+        }
+        let written =
+            |path: &Path, e: io::Error| format!("Could not write {}: {e}", path.display());
+        if self.format == ImageFormat::Gif {
+            self.write_gif(frames, path).map_err(|e| written(path, e))?;
+            return Ok(vec![path.to_path_buf()]);
+        }
+        fs::create_dir_all(path).map_err(|e| written(path, e))?;
+        let digits = frames.len().to_string().len();
+        let mut paths = Vec::new();
+        for (i, frame) in frames.iter().enumerate() {
+            let file = path.join(format!("frame-{i:0digits$}.{}", self.format.extension()));
+            let (width, height, rgb) = self.scaled(frame);
+            let out = File::create(&file).map(BufWriter::new);
+            match self.format {
+                ImageFormat::Ppm => out.and_then(|out| write_ppm(out, width, height, &rgb)),
+                _ => out.and_then(|out| write_png(out, width, height, &rgb)),
+            }
+            .map_err(|e| written(&file, e))?;
+            paths.push(file);
+        }
+        Ok(paths)
+    }
+
+    /// Width and height in pixels, and the red, green and blue of each
+    fn scaled(&self, frame: &Frame) -> (usize, usize, Vec<u8>) {
+        let scale = self.scale.max(1);
+        let (width, height) = (frame.width() * scale, frame.height() * scale);
+        let mut rgb = Vec::with_capacity(width * height * 3);
+        for y in 0..height {
+            for x in 0..width {
+                let Rgb(r, g, b) = frame.get(x / scale, y / scale);
+                rgb.extend([r, g, b]);
+            }
+        }
+        (width, height, rgb)
+    }
+
+    /// Uses one palette for the whole animation if it has few enough colours,
+    /// or else one per frame
+    fn write_gif(&self, frames: &[Frame], path: &Path) -> io::Result<()> {
+        let mut colours = HashMap::new();
+        for pixel in frames.iter().flat_map(|f| f.pixels()) {
+            let next = colours.len();
+            colours.entry(*pixel).or_insert(next);
+        }
+        let palette = if colours.len() <= 256 {
+            let mut palette = vec![0; colours.len() * 3];
+            for (Rgb(r, g, b), &i) in &colours {
+                palette[i * 3..i * 3 + 3].copy_from_slice(&[*r, *g, *b]);
+            }
+            Some(palette)
+        } else {
+            None
+        };
+
+        let (width, height, _) = self.scaled(&frames[0]);
+        let size = |n: usize| u16::try_from(n).map_err(|_| io::Error::other("Too big for a GIF"));
+        let (width, height) = (size(width)?, size(height)?);
+        let out = BufWriter::new(File::create(path)?);
+        let mut encoder = gif::Encoder::new(out, width, height, palette.as_deref().unwrap_or(&[]))
+            .map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+        for frame in frames {
+            let (_, _, rgb) = self.scaled(frame);
+            let mut gif_frame = match palette {
+                Some(_) => {
+                    let indices = rgb
+                        .chunks(3)
+                        .map(|c| colours[&Rgb(c[0], c[1], c[2])] as u8)
+                        .collect::<Vec<_>>();
+                    gif::Frame::from_indexed_pixels(width, height, indices, None)
+                }
+                None => gif::Frame::from_rgb_speed(width, height, &rgb, 10),
+            };
+            gif_frame.delay = self.delay;
+            encoder.write_frame(&gif_frame).map_err(io::Error::other)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_ppm(mut out: impl Write, width: usize, height: usize, rgb: &[u8]) -> io::Result<()> {
+    write!(out, "P6\n{width} {height}\n255\n")?;
+    out.write_all(rgb)?;
+    out.flush()
+}
+
+fn write_png(out: impl Write, width: usize, height: usize, rgb: &[u8]) -> io::Result<()> {
+    let size = |n: usize| u32::try_from(n).map_err(|_| io::Error::other("Too big for a PNG"));
+    let mut encoder = png::Encoder::new(out, size(width)?, size(height)?);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    writer.write_image_data(rgb).map_err(io::Error::other)?;
+    writer.finish().map_err(io::Error::other)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    fn frames() -> Vec<Frame> {
+        (0..3)
+            .map(|i| {
+                Frame::new(
+                    3,
+                    2,
+                    |x, y| if x == i { Rgb::RED } else { Rgb(0, 0, y as u8) },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_ppm() {
+        let dir = env::temp_dir().join(format!("aoc-ppm-{}", std::process::id()));
+        let export = Export {
+            format: ImageFormat::Ppm,
+            scale: 2,
+            delay: 5,
+        };
+        let paths = export.write(&frames(), &dir).unwrap();
+        assert_eq!(
+            vec![
+                dir.join("frame-0.ppm"),
+                dir.join("frame-1.ppm"),
+                dir.join("frame-2.ppm")
+            ],
+            paths
+        );
+        let ppm = fs::read(&paths[1]).unwrap();
+        let header = b"P6\n6 4\n255\n";
+        assert_eq!(header, &ppm[..header.len()]);
+        assert_eq!(header.len() + 6 * 4 * 3, ppm.len());
+        // The second cell of the first row is red in the second frame
+        let pixel = header.len() + 2 * 3;
+        assert_eq!([230, 50, 50], ppm[pixel..pixel + 3]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_png_and_gif() {
+        let dir = env::temp_dir().join(format!("aoc-png-{}", std::process::id()));
+        let export = Export {
+            format: ImageFormat::Png,
+            scale: 1,
+            delay: 5,
+        };
+        let paths = export.write(&frames(), &dir).unwrap();
+        assert_eq!(3, paths.len());
+        assert!(fs::read(&paths[0]).unwrap().starts_with(b"\x89PNG"));
+
+        let gif = dir.join("frames.gif");
+        let export = Export {
+            format: ImageFormat::Gif,
+            ..export
+        };
+        assert_eq!(vec![gif.clone()], export.write(&frames(), &gif).unwrap());
+        assert!(fs::read(&gif).unwrap().starts_with(b"GIF89a"));
+
+        assert!(export.write(&[], &gif).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
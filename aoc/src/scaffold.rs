@@ -0,0 +1,226 @@
+use std::{fs, io, path::Path};
+
+/// Creates `dayN` from `template/` and registers it with the workspace and
+/// the runner
+pub fn new_day(root: &Path, day: u8) -> Result<(), String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day {day} is not in the calendar"));
+    }
+    let name = format!("day{day}");
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    copy_template(&root.join("template"), &dir, day)
+        .map_err(|e| format!("Could not create {}: {e}", dir.display()))?;
+    write(&dir.join("input.txt"), String::new())?;
+
+    edit(&root.join("Cargo.toml"), |s| add_member(s, &name))?;
+    edit(&root.join("aoc").join("Cargo.toml"), |s| {
+        let s = insert_sorted(
+            s,
+            day,
+            |l| l.strip_prefix("day")?.split_once(" = ")?.0.parse().ok(),
+            &format!("{name} = {{ path = \"../{name}\" }}"),
+        )?;
+        insert_sorted(
+            &s,
+            day,
+            |l| {
+                l.trim()
+                    .strip_prefix("\"day")?
+                    .split_once('/')?
+                    .0
+                    .parse()
+                    .ok()
+            },
+            &format!("    \"{name}/embedded-input\","),
+        )
+    })?;
+    edit(&root.join("aoc").join("src").join("days.rs"), |s| {
+        let s = insert_sorted(
+            s,
+            day,
+            |l| {
+                l.trim()
+                    .strip_prefix("Day::new::<day")?
+                    .split_once(':')?
+                    .0
+                    .parse()
+                    .ok()
+            },
+            &format!("    Day::new::<{name}::Day{day}>({name}::EMBEDDED_INPUT),"),
+        )?;
+        let count = s.lines().filter(|l| l.contains("Day::new::<")).count();
+        let (before, after) = s
+            .split_once("[Day; ")
+            .ok_or("Could not find the DAYS table")?;
+        let (_, after) = after
+            .split_once(']')
+            .ok_or("Could not find the DAYS table")?;
+        Ok(format!("{before}[Day; {count}]{after}"))
+    })
+}
+
+fn copy_template(from: &Path, to: &Path, day: u8) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let dest = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_template(&entry.path(), &dest, day)?;
+        } else {
+            let contents = fs::read_to_string(entry.path())?;
+            fs::write(dest, contents.replace("{{day}}", &day.to_string()))?;
+        }
+    }
+    Ok(())
+}
+
+fn write(path: &Path, contents: String) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("Could not write {}: {e}", path.display()))
+}
+
+fn edit(path: &Path, f: impl FnOnce(&str) -> Result<String, String>) -> Result<(), String> {
+    let contents =
+        fs::read_to_string(path).map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+    let contents = f(&contents).map_err(|e| format!("{}: {e}", path.display()))?;
+    write(path, contents)
+}
+
+/// Adds `name` to the workspace members, keeping them sorted
+fn add_member(manifest: &str, name: &str) -> Result<String, String> {
+    let mut found = false;
+    let mut lines = Vec::new();
+    for l in manifest.lines() {
+        match l
+            .strip_prefix("members = [")
+            .and_then(|m| m.strip_suffix(']'))
+        {
+            Some(members) => {
+                let mut members = members
+                    .split(',')
+                    .map(|m| m.trim().trim_matches('"'))
+                    .filter(|m| !m.is_empty())
+                    .collect::<Vec<_>>();
+                if members.contains(&name) {
+                    return Err(format!("{name} is already a member"));
+                }
+                members.push(name);
+                members.sort_unstable();
+                let members = members
+                    .iter()
+                    .map(|m| format!("\"{m}\""))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                lines.push(format!("members = [{members}]"));
+                found = true;
+            }
+            None => lines.push(l.to_owned()),
+        }
+    }
+    if !found {
+        return Err("Could not find the workspace members".to_owned());
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Inserts `line` among the lines that `day_of` recognises, in day order
+fn insert_sorted(
+    s: &str,
+    day: u8,
+    day_of: impl Fn(&str) -> Option<u8>,
+    line: &str,
+) -> Result<String, String> {
+    let mut lines = s.lines().collect::<Vec<_>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| day_of(l).map(|d| (i, d)))
+        .collect::<Vec<_>>();
+    let index = match days.iter().rev().find(|&&(_, d)| d < day) {
+        Some(&(i, _)) => i + 1,
+        None => days
+            .first()
+            .map(|&(i, _)| i)
+            .ok_or_else(|| format!("Could not find where to add `{}`", line.trim()))?,
+    };
+    lines.insert(index, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_member() {
+        let manifest = "[workspace]\nmembers = [\"aoc\", \"day1\", \"day2\", \"grid\"]\n";
+        assert_eq!(
+            "[workspace]\nmembers = [\"aoc\", \"day1\", \"day10\", \"day2\", \"grid\"]\n",
+            add_member(manifest, "day10").unwrap()
+        );
+        assert!(add_member(manifest, "day2").is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        fs::create_dir_all(root.join("template").join("src")).unwrap();
+        fs::create_dir_all(root.join("template").join("examples")).unwrap();
+        fs::write(root.join("template/examples/example.txt"), "").unwrap();
+        fs::write(
+            root.join("template").join("src").join("main.rs"),
+            "day{{day}}::Day{{day}}",
+        )
+        .unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"aoc\", \"day1\", \"day3\"]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("Cargo.toml"),
+            "[dependencies]\nday1 = { path = \"../day1\" }\nday3 = { path = \"../day3\" }\n\n\
+             [features]\nembedded-input = [\n    \"day1/embedded-input\",\n    \"day3/embedded-input\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("src").join("days.rs"),
+            "pub static DAYS: [Day; 2] = [\n    Day::new::<day1::Day1>(day1::EMBEDDED_INPUT),\n    \
+             Day::new::<day3::Day3>(day3::EMBEDDED_INPUT),\n];\n",
+        )
+        .unwrap();
+
+        new_day(&root, 2).unwrap();
+        let main = fs::read_to_string(root.join("day2/src/main.rs")).unwrap();
+        assert_eq!("day2::Day2", main);
+        assert_eq!(
+            "",
+            fs::read_to_string(root.join("day2/examples/example.txt")).unwrap()
+        );
+        assert!(root.join("day2/input.txt").is_file());
+        assert!(fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("[\"aoc\", \"day1\", \"day2\", \"day3\"]"));
+        assert!(fs::read_to_string(root.join("aoc/Cargo.toml"))
+            .unwrap()
+            .contains("day1/embedded-input\",\n    \"day2/embedded-input\","));
+        let days = fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+        assert!(days.starts_with("pub static DAYS: [Day; 3]"));
+        assert!(days.contains("Day1>(day1::EMBEDDED_INPUT),\n    Day::new::<day2::Day2>"));
+
+        // Refuses to touch an existing day
+        fs::write(root.join("day2/src/lib.rs"), "edited").unwrap();
+        assert!(new_day(&root, 2).is_err());
+        assert_eq!(
+            "edited",
+            fs::read_to_string(root.join("day2/src/lib.rs")).unwrap()
+        );
+        assert!(new_day(&root, 26).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,106 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::input::workspace_dir;
+
+/// Checked-in answers for the author's puzzle inputs, one `<day> <part>
+/// <answer>` per line
+pub const MANIFEST: &str = "answers.txt";
+
+const HEADER: &str = "\
+# Expected answers for the real puzzle inputs, one `<day> <part> <answer>` per
+# line. Days without a local input are skipped by the answer tests.
+# Regenerate with `aoc --record`.
+";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u8, u8), String>);
+
+impl Answers {
+    pub fn path() -> PathBuf {
+        workspace_dir().join(MANIFEST)
+    }
+
+    /// Reads the manifest, treating a missing file as having no answers
+    pub fn load() -> Result<Self, String> {
+        Self::read(&Self::path())
+    }
+
+    /// Reads answers in the manifest's format from `path`, treating a missing
+    /// file as having no answers
+    pub fn read(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Could not read {}: {e}", path.display())),
+        }
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        fs::write(Self::path(), self.to_string())
+    }
+
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut answers = BTreeMap::new();
+        for (i, l) in s.lines().enumerate() {
+            let l = l.trim();
+            if l.is_empty() || l.starts_with('#') {
+                continue;
+            }
+            let mut fields = l.splitn(3, char::is_whitespace);
+            let mut number = |name| {
+                fields
+                    .next()
+                    .and_then(|n| n.parse::<u8>().ok())
+                    .ok_or_else(|| format!("line {}: invalid {name} in `{l}`", i + 1))
+            };
+            let key = (number("day")?, number("part")?);
+            let answer = fields
+                .next()
+                .map(str::trim)
+                .filter(|a| !a.is_empty())
+                .ok_or_else(|| format!("line {}: missing answer in `{l}`", i + 1))?;
+            answers.insert(key, answer.to_owned());
+        }
+        Ok(Self(answers))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, answer: String) {
+        self.0.insert((day, part), answer);
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(HEADER)?;
+        for ((day, part), answer) in &self.0 {
+            writeln!(f, "{day} {part} {answer}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("# comment\n1 1 11\n\n17 1 4,6,3,5\n").unwrap();
+        assert_eq!(Some("11"), answers.get(1, 1));
+        assert_eq!(Some("4,6,3,5"), answers.get(17, 1));
+        assert_eq!(None, answers.get(1, 2));
+        assert_eq!(answers, Answers::parse(&answers.to_string()).unwrap());
+
+        assert!(Answers::parse("1 x 11").is_err());
+        assert!(Answers::parse("1 2").is_err());
+    }
+}
@@ -0,0 +1,136 @@
+use std::{
+    env,
+    fmt::Debug,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::generate::{SeedableRng, SmallRng};
+
+/// Environment variable overriding the seed of the first random case
+pub const SEED_ENV: &str = "AOC_SEED";
+/// Environment variable overriding how many random cases are checked
+pub const CASES_ENV: &str = "AOC_CASES";
+
+/// Checks a fast solver against a slow reference implementation on random
+/// puzzle inputs, so an optimisation can't quietly change an answer.
+///
+/// Each case gets its own seed, which the failure message reports so the case
+/// can be rerun on its own with `AOC_SEED=<seed> AOC_CASES=1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Differential {
+    pub seed: u64,
+    pub cases: u64,
+}
+
+impl Default for Differential {
+    fn default() -> Self {
+        let var = |name, default| {
+            env::var(name)
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default)
+        };
+        Self {
+            seed: var(SEED_ENV, 2024),
+            cases: var(CASES_ENV, 200),
+        }
+    }
+}
+
+impl Differential {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Checks `cases` inputs, unless `AOC_CASES` says otherwise
+    pub fn cases(self, cases: u64) -> Self {
+        match env::var(CASES_ENV) {
+            Ok(_) => self,
+            Err(_) => Self { cases, ..self },
+        }
+    }
+
+    /// Runs `oracle` and `fast` on inputs made by `generate`, panicking with the
+    /// input if they disagree or either of them panics
+    pub fn check<T: PartialEq + Debug>(
+        &self,
+        mut generate: impl FnMut(&mut SmallRng) -> String,
+        oracle: impl Fn(&str) -> T,
+        fast: impl Fn(&str) -> T,
+    ) {
+        for case in 0..self.cases {
+            let seed = self.seed.wrapping_add(case);
+            let input = generate(&mut SmallRng::seed_from_u64(seed));
+            let run = |solver: &dyn Fn(&str) -> T| {
+                panic::catch_unwind(AssertUnwindSafe(|| solver(&input)))
+                    .map_err(|e| panic_message(e.as_ref()))
+            };
+            let (expected, actual) = (run(&oracle), run(&fast));
+            if expected != actual {
+                panic!(
+                    "Case {case} differs (rerun with {SEED_ENV}={seed} {CASES_ENV}=1)\n\
+                     oracle: {expected:?}\n\
+                     fast:   {actual:?}\n\
+                     input:\n{input}"
+                );
+            }
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown");
+    format!("panicked: {message}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::Rng;
+
+    fn numbers(rng: &mut SmallRng) -> String {
+        let n = rng.gen_range(0..10);
+        (0..n)
+            .map(|_| rng.gen_range(0..100).to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn sum(input: &str) -> u32 {
+        input
+            .split_whitespace()
+            .map(|n| n.parse::<u32>().unwrap())
+            .sum()
+    }
+
+    #[test]
+    fn test_agreeing() {
+        let differential = Differential { seed: 1, cases: 50 };
+        differential.check(numbers, sum, |input| {
+            input
+                .split_whitespace()
+                .fold(0, |acc, n| acc + n.parse::<u32>().unwrap())
+        });
+    }
+
+    #[test]
+    fn test_diverging() {
+        let differential = Differential { seed: 1, cases: 50 };
+        let result = panic::catch_unwind(|| {
+            differential.check(numbers, sum, |input| {
+                input
+                    .split_whitespace()
+                    .map(|n| n.parse::<u32>().unwrap())
+                    .max()
+                    .unwrap_or(0)
+            })
+        });
+        let message = panic_message(result.unwrap_err().as_ref());
+        assert!(message.contains("rerun with AOC_SEED="), "{message}");
+        assert!(message.contains("\ninput:\n"), "{message}");
+    }
+}
@@ -0,0 +1,179 @@
+//! Examples from the puzzle descriptions, kept as files in each day's
+//! `examples` directory. `NAME.txt` is the input, `NAME.answers` the answers it
+//! should give in the `<day> <part> <answer>` format of `answers.txt`, and the
+//! optional `NAME.params` any parameters it needs as `name=value` lines.
+//!
+//! [`example_tests!`](crate::example_tests) turns the directory into a test,
+//! so a new case needs no code.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{answers::Answers, Day, Params, Solution};
+
+/// Name of the directory of examples in each day's crate
+pub const DIR: &str = "examples";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub answers: Answers,
+    pub params: Params,
+}
+
+/// Every example in `dir`, by name
+pub fn load(dir: &Path) -> Result<Vec<Example>, String> {
+    let read_error = |path: &Path, e| format!("Could not read {}: {e}", path.display());
+    let mut paths = fs::read_dir(dir)
+        .map_err(|e| read_error(dir, e))?
+        .map(|entry| entry.map(|e| e.path()).map_err(|e| read_error(dir, e)))
+        .collect::<Result<Vec<PathBuf>, _>>()?;
+    paths.retain(|p| p.extension().is_some_and(|ext| ext == "txt"));
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| {
+            Ok(Example {
+                name: path
+                    .file_stem()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_default(),
+                input: fs::read_to_string(&path).map_err(|e| read_error(&path, e))?,
+                answers: Answers::read(&path.with_extension("answers"))?,
+                params: Params::read(&path.with_extension("params"))?,
+            })
+        })
+        .collect()
+}
+
+/// Runs `S` on every example in `dir`, panicking with a list of the answers
+/// that differ from those expected
+pub fn check<S: Solution + 'static>(dir: &Path) {
+    let examples = load(dir).unwrap_or_else(|e| panic!("{e}"));
+    assert!(!examples.is_empty(), "No examples in {}", dir.display());
+    let day = Day::new::<S>(None);
+    let mut failures = Vec::new();
+    for example in examples {
+        let name = &example.name;
+        let parts = [1, 2]
+            .into_iter()
+            .filter(|&p| example.answers.get(S::DAY, p).is_some())
+            .collect::<Vec<_>>();
+        if parts.is_empty() {
+            failures.push(format!("{name}: No answers for day {}", S::DAY));
+            continue;
+        }
+        let run = match day.run(&example.input, &example.params, &parts) {
+            Ok(run) => run,
+            Err(e) => {
+                failures.push(format!("{name}: {e}"));
+                continue;
+            }
+        };
+        for p in run.parts {
+            let expected = example.answers.get(S::DAY, p.part).unwrap();
+            if p.answer != expected {
+                failures.push(format!(
+                    "{name} part {}: expected {expected}, got {}",
+                    p.part, p.answer
+                ));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Adds a test that checks a day against every example in its crate's
+/// `examples` directory
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        #[test]
+        fn test_examples() {
+            $crate::examples::check::<$solution>(
+                &::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($crate::examples::DIR),
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::{
+        generate::{Generated, SmallRng},
+        Param, ParseError,
+    };
+
+    const SCALE: Param = Param {
+        name: "scale",
+        default: "1",
+        help: "Multiplies the sum",
+    };
+
+    /// Sums the numbers on each line, times `scale`
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        type Input<'a> = (Vec<u32>, u32);
+        type PartOne = u32;
+        type PartTwo = usize;
+
+        const PARAMS: &'static [Param] = &[SCALE];
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            Self::parse_with(input, &Params::default())
+        }
+
+        fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+            let numbers = input
+                .lines()
+                .map(|l| l.parse().map_err(|_| ParseError::new("Not a number")))
+                .collect::<Result<_, _>>()?;
+            Ok((numbers, params.get(&SCALE)?))
+        }
+
+        fn part_one((numbers, scale): &Self::Input<'_>) -> u32 {
+            numbers.iter().sum::<u32>() * scale
+        }
+
+        fn part_two((numbers, _): &Self::Input<'_>) -> usize {
+            numbers.len()
+        }
+
+        fn generate(_: &mut SmallRng, _: usize) -> Generated {
+            Generated::default()
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let dir = env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("small.txt"), "1\n2\n").unwrap();
+        fs::write(dir.join("small.answers"), "1 1 3\n1 2 2\n").unwrap();
+        fs::write(dir.join("scaled.txt"), "1\n2\n").unwrap();
+        fs::write(dir.join("scaled.answers"), "1 1 30\n").unwrap();
+        fs::write(dir.join("scaled.params"), "scale=10\n").unwrap();
+
+        let examples = load(&dir).unwrap();
+        let names = examples.iter().map(|e| e.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(vec!["scaled", "small"], names);
+        assert_eq!(Ok(10), examples[0].params.get::<u32>(&SCALE));
+        check::<Sum>(&dir);
+
+        fs::write(dir.join("scaled.answers"), "1 1 31\n").unwrap();
+        let failure = std::panic::catch_unwind(|| check::<Sum>(&dir)).unwrap_err();
+        assert_eq!(
+            Some(&"scaled part 1: expected 31, got 30".to_owned()),
+            failure.downcast_ref::<String>()
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{Param, Params};
+
+pub use rand::{rngs::SmallRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// A random puzzle input made by [`crate::Solution::generate`], along with any
+/// parameter values it was made for
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    /// Values for parameters whose defaults don't suit the input, such as the
+    /// size of a smaller grid
+    pub params: Params,
+}
+
+impl Generated {
+    pub fn new(input: String) -> Self {
+        Self {
+            input,
+            params: Params::default(),
+        }
+    }
+
+    /// Runs the input with `value` for `param`
+    pub fn with(mut self, param: &Param, value: impl ToString) -> Self {
+        self.params.insert(param.name, value.to_string());
+        self
+    }
+}
+
+impl From<String> for Generated {
+    fn from(input: String) -> Self {
+        Self::new(input)
+    }
+}
+
+/// A grid of `width` by `height` characters from `cell(x, y)`, each row ending
+/// in a newline
+pub fn grid(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> char) -> String {
+    let mut grid = String::with_capacity((width + 1) * height);
+    for y in 0..height {
+        grid.extend((0..width).map(|x| cell(x, y)));
+        grid.push('\n');
+    }
+    grid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated() {
+        const SIZE: Param = Param {
+            name: "size",
+            default: "70",
+            help: "Width of the grid",
+        };
+        let generated = Generated::new(grid(3, 2, |x, y| if x == y { '#' } else { '.' }));
+        assert_eq!("#..\n.#.\n", generated.input);
+        let generated = generated.with(&SIZE, 2);
+        assert_eq!(Ok(2), generated.params.get::<usize>(&SIZE));
+    }
+}
@@ -0,0 +1,214 @@
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable pointing at a directory of puzzle inputs
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+
+/// Where to look for a day's puzzle input.
+///
+/// Resolution order is:
+/// 1. `path`, if given (`-` reads stdin)
+/// 2. the input embedded with the `embedded-input` feature
+/// 3. `dayN.txt` or `dayN/input.txt` inside `dir`
+/// 4. `dayN/input.txt` in the workspace
+#[derive(Debug, Default, Clone)]
+pub struct InputConfig {
+    pub path: Option<PathBuf>,
+    pub dir: Option<PathBuf>,
+}
+
+impl InputConfig {
+    /// Config with the inputs directory taken from `AOC_INPUTS`, if set
+    pub fn from_env() -> Self {
+        Self {
+            path: None,
+            dir: env::var_os(INPUTS_ENV).map(PathBuf::from),
+        }
+    }
+
+    pub fn load(&self, day: u8, embedded: Option<&'static str>) -> Result<String, InputError> {
+        self.locate(day, embedded)?.read()
+    }
+
+    /// Finds where a day's input would be loaded from, without reading it
+    pub fn locate(&self, day: u8, embedded: Option<&'static str>) -> Result<Source, InputError> {
+        if let Some(path) = &self.path {
+            return Ok(Source::from_path(path));
+        }
+        if let Some(input) = embedded {
+            return Ok(Source::Embedded(input));
+        }
+        let searched = self.candidates(day);
+        match searched.iter().find(|p| p.is_file()) {
+            Some(path) => Ok(Source::File(path.clone())),
+            None => Err(InputError::NotFound { day, searched }),
+        }
+    }
+
+    fn candidates(&self, day: u8) -> Vec<PathBuf> {
+        match &self.dir {
+            Some(dir) => vec![
+                dir.join(format!("day{day}.txt")),
+                dir.join(format!("day{day}")).join("input.txt"),
+            ],
+            None => vec![workspace_dir().join(format!("day{day}")).join("input.txt")],
+        }
+    }
+}
+
+/// Where a puzzle input comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Embedded(&'static str),
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// A file, or stdin for `-`
+    pub fn from_path(path: &Path) -> Self {
+        if path == Path::new("-") {
+            Source::Stdin
+        } else {
+            Source::File(path.to_path_buf())
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Source::Embedded(input) => Ok((*input).to_owned()),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map(|_| input)
+                    .map_err(|source| InputError::Io {
+                        path: PathBuf::from("-"),
+                        source,
+                    })
+            }
+            Source::File(path) => fs::read_to_string(path).map_err(|source| InputError::Io {
+                path: path.clone(),
+                source,
+            }),
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Embedded(_) => f.write_str("embedded"),
+            Source::Stdin => f.write_str("stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Root of the cargo workspace the solutions were built from
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("common is a member of the workspace")
+        .to_path_buf()
+}
+
+/// Loads input for a day binary, taking an optional path (or `-`) as the first
+/// argument. Prints the error and exits if no input can be found.
+pub fn from_args(day: u8, embedded: Option<&'static str>) -> String {
+    let config = InputConfig {
+        path: env::args_os().nth(1).map(PathBuf::from),
+        ..InputConfig::from_env()
+    };
+    config.load(day, embedded).unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    })
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound { day: u8, searched: Vec<PathBuf> },
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound { day, searched } => {
+                write!(f, "No input found for day {day}. Looked in:")?;
+                for path in searched {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                write!(
+                    f,
+                    "\nPass a path (or `-` for stdin), or set {INPUTS_ENV} to a directory of inputs"
+                )
+            }
+            InputError::Io { path, source } => {
+                write!(f, "Could not read {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::NotFound { .. } => None,
+            InputError::Io { source, .. } => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedded_input() {
+        let config = InputConfig::default();
+        assert_eq!("1 2 3", config.load(1, Some("1 2 3")).unwrap());
+        assert_eq!("embedded", config.locate(1, Some("")).unwrap().to_string());
+    }
+
+    #[test]
+    fn test_inputs_dir() {
+        let dir = env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(dir.join("day2")).unwrap();
+        fs::write(dir.join("day1.txt"), "flat").unwrap();
+        fs::write(dir.join("day2").join("input.txt"), "nested").unwrap();
+        let config = InputConfig {
+            path: None,
+            dir: Some(dir.clone()),
+        };
+
+        assert_eq!(
+            Source::File(dir.join("day1.txt")),
+            config.locate(1, None).unwrap()
+        );
+        assert_eq!("flat", config.load(1, None).unwrap());
+        assert_eq!("nested", config.load(2, None).unwrap());
+        let err = config.load(3, None).unwrap_err();
+        assert!(
+            matches!(err, InputError::NotFound { day: 3, ref searched } if searched.len() == 2)
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_missing_path() {
+        let config = InputConfig {
+            path: Some(PathBuf::from("definitely/not/here.txt")),
+            dir: None,
+        };
+        let err = config.load(1, Some("ignored")).unwrap_err();
+        assert!(matches!(err, InputError::Io { .. }));
+    }
+}
@@ -0,0 +1,15 @@
+pub mod answers;
+pub mod differential;
+pub mod examples;
+pub mod generate;
+pub mod input;
+pub mod logging;
+pub mod memory;
+pub mod params;
+pub mod parse;
+pub mod render;
+pub mod solution;
+
+pub use params::{Param, Params};
+pub use parse::ParseError;
+pub use solution::{Day, Solution};
@@ -0,0 +1,159 @@
+use std::{env, io::Write};
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Environment variable with extra log filters, in the same form as `--log`
+pub const LOG_ENV: &str = "AOC_LOG";
+
+/// Sends the days' diagnostic logging to stderr, leaving stdout for answers.
+///
+/// Every day logs at `verbosity` (0 for warnings, then info, debug and trace),
+/// overridden by the comma separated directives in `$AOC_LOG` and then
+/// `filters`. A directive is a level (`debug`), or a day and a level
+/// (`14=debug` or `day14=debug`).
+pub fn init(verbosity: u8, filters: Option<&str>) {
+    let level = match verbosity {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    let mut levels = Levels {
+        default: level,
+        days: Vec::new(),
+    };
+    if let Ok(env) = env::var(LOG_ENV) {
+        levels.add(&env);
+    }
+    if let Some(filters) = filters {
+        levels.add(filters);
+    }
+    // The levels are checked here, so the inner logger lets everything through
+    let inner = env_logger::Builder::new()
+        .filter_level(LevelFilter::Trace)
+        .format(|buf, record| {
+            writeln!(
+                buf,
+                "{:>5} {}: {}",
+                record.level(),
+                record.target(),
+                record.args()
+            )
+        })
+        .target(env_logger::Target::Stderr)
+        .build();
+    let max = levels.max();
+    // Tests and repeated runs may have set it up already
+    if log::set_boxed_logger(Box::new(Logger { inner, levels })).is_ok() {
+        log::set_max_level(max);
+    }
+}
+
+/// The level each day logs at. env_logger matches targets by prefix, so its
+/// `day2` filter would also catch `day21`; these match the crate exactly.
+#[derive(Debug)]
+struct Levels {
+    default: LevelFilter,
+    /// Crate names and their levels, later ones taking precedence
+    days: Vec<(String, LevelFilter)>,
+}
+
+impl Levels {
+    /// Adds the comma separated directives in `filters`, warning about and
+    /// skipping any that don't parse
+    fn add(&mut self, filters: &str) {
+        for d in filters.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            let (day, level) = match d.split_once('=') {
+                Some((day, level)) => (Some(day.trim()), level.trim()),
+                None => (None, d),
+            };
+            let Ok(level) = level.parse() else {
+                eprintln!("warning: invalid log level in `{d}`");
+                continue;
+            };
+            match day {
+                None => self.default = level,
+                Some(day) if day.parse::<u8>().is_ok() => {
+                    self.days.push((format!("day{day}"), level))
+                }
+                Some(day) => self.days.push((day.to_owned(), level)),
+            }
+        }
+    }
+
+    /// The level for a record from `target`, going by the crate it's in
+    fn level(&self, target: &str) -> LevelFilter {
+        let krate = target.split("::").next().unwrap_or(target);
+        self.days
+            .iter()
+            .rev()
+            .find(|(day, _)| day == krate)
+            .map_or(self.default, |&(_, level)| level)
+    }
+
+    /// The most verbose level any crate logs at
+    fn max(&self) -> LevelFilter {
+        self.days
+            .iter()
+            .map(|&(_, level)| level)
+            .fold(self.default, Ord::max)
+    }
+}
+
+struct Logger {
+    inner: env_logger::Logger,
+    levels: Levels,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.levels.level(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn levels(filters: &str) -> Levels {
+        let mut levels = Levels {
+            default: LevelFilter::Warn,
+            days: Vec::new(),
+        };
+        levels.add(filters);
+        levels
+    }
+
+    #[test]
+    fn test_levels() {
+        let l = levels("14=debug");
+        assert_eq!(LevelFilter::Debug, l.level("day14"));
+        assert_eq!(LevelFilter::Debug, l.level("day14::grid"));
+        assert_eq!(LevelFilter::Warn, l.level("day1"));
+
+        let l = levels("info, 21=trace,day6=off,6=error");
+        assert_eq!(LevelFilter::Info, l.level("day2"));
+        assert_eq!(LevelFilter::Trace, l.level("day21"));
+        assert_eq!(LevelFilter::Error, l.level("day6"));
+        assert_eq!(LevelFilter::Trace, l.max());
+    }
+
+    #[test]
+    fn test_levels_exact_day() {
+        let l = levels("2=debug,1=trace");
+        assert_eq!(LevelFilter::Debug, l.level("day2"));
+        assert_eq!(LevelFilter::Warn, l.level("day21"));
+        assert_eq!(LevelFilter::Warn, l.level("day10"));
+        assert_eq!(LevelFilter::Warn, l.level("day19::part_two"));
+    }
+}
@@ -0,0 +1,187 @@
+//! Heap usage of the solutions. [`CountingAlloc`] counts the allocations made
+//! on each thread, and [`measure`] reports those made while running a closure.
+//! Binaries opt in by installing it:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOC: common::memory::CountingAlloc = common::memory::CountingAlloc;
+//! ```
+//!
+//! Counts are per thread, so parts run concurrently are measured separately,
+//! but allocations a part makes on other threads aren't counted. Without the
+//! allocator installed every [`Usage`] is zero.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+/// The system allocator, counting what each thread allocates
+pub struct CountingAlloc;
+
+thread_local! {
+    static COUNTS: Cell<Counts> = const { Cell::new(Counts::ZERO) };
+}
+
+/// Running totals for the current measurement on one thread
+#[derive(Debug, Clone, Copy)]
+struct Counts {
+    allocations: u64,
+    allocated: u64,
+    /// Bytes allocated less bytes freed. Negative when freeing memory from
+    /// before the measurement started.
+    live: i64,
+    peak: i64,
+}
+
+impl Counts {
+    const ZERO: Counts = Counts {
+        allocations: 0,
+        allocated: 0,
+        live: 0,
+        peak: 0,
+    };
+}
+
+fn update(f: impl FnOnce(&mut Counts)) {
+    // Ignore allocations made while the thread is being torn down
+    _ = COUNTS.try_with(|counts| {
+        let mut c = counts.get();
+        f(&mut c);
+        counts.set(c);
+    });
+}
+
+fn allocated(size: usize) {
+    update(|c| {
+        c.allocations += 1;
+        c.allocated += size as u64;
+        c.live += size as i64;
+        c.peak = c.peak.max(c.live);
+    });
+}
+
+fn freed(size: usize) {
+    update(|c| c.live -= size as i64);
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        freed(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new.is_null() {
+            // Counted as a new allocation, since growing a buffer usually
+            // copies it
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new
+    }
+}
+
+/// What a piece of code allocated on the heap
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    /// Calls to allocate or grow memory
+    pub allocations: u64,
+    /// Total bytes allocated, including any since freed
+    pub allocated: u64,
+    /// Most bytes held at once, beyond what was held before
+    pub peak: u64,
+}
+
+/// Runs `f`, returning what it allocated on this thread. Measurements can be
+/// nested, with the outer one including the inner.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let outer = COUNTS.with(|c| c.replace(Counts::ZERO));
+    let result = f();
+    let inner = COUNTS.with(|c| c.get());
+    COUNTS.with(|c| {
+        c.set(Counts {
+            allocations: outer.allocations + inner.allocations,
+            allocated: outer.allocated + inner.allocated,
+            live: outer.live + inner.live,
+            peak: outer.peak.max(outer.live + inner.peak),
+        })
+    });
+    let usage = Usage {
+        allocations: inner.allocations,
+        allocated: inner.allocated,
+        peak: inner.peak.max(0) as u64,
+    };
+    (result, usage)
+}
+
+/// `bytes` in the largest unit that keeps it at least 1, such as `1.50MiB`
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes}B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.2}{}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOC: CountingAlloc = CountingAlloc;
+
+    #[test]
+    fn test_measure() {
+        let (_, empty) = measure(|| 1 + 1);
+        assert_eq!(Usage::default(), empty);
+
+        let (_, usage) = measure(|| {
+            let a = vec![0u8; 1000];
+            drop(a);
+            let (b, inner) = measure(|| vec![0u8; 500]);
+            assert_eq!(
+                Usage {
+                    allocations: 1,
+                    allocated: 500,
+                    peak: 500
+                },
+                inner
+            );
+            b
+        });
+        assert_eq!(2, usage.allocations);
+        assert_eq!(1500, usage.allocated);
+        assert_eq!(1000, usage.peak);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!("512B", format_bytes(512));
+        assert_eq!("1.50KiB", format_bytes(1536));
+        assert_eq!("3.00MiB", format_bytes(3 << 20));
+    }
+}
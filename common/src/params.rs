@@ -0,0 +1,151 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+    str::FromStr,
+};
+
+use crate::ParseError;
+
+/// Environment variable with parameter values, as `name=value,name=value`
+pub const PARAMS_ENV: &str = "AOC_PARAMS";
+
+/// A tunable puzzle parameter, such as a grid size, that differs between the
+/// examples and the real puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    /// Value for the real puzzle
+    pub default: &'static str,
+    pub help: &'static str,
+}
+
+/// Values given for parameters at runtime, by name
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    /// Values from `AOC_PARAMS`, if set
+    pub fn from_env() -> Result<Self, String> {
+        match env::var(PARAMS_ENV) {
+            Ok(s) => s.parse().map_err(|e| format!("{PARAMS_ENV}: {e}")),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// Values from a file of `name=value` lines, treating a missing file as
+    /// giving none
+    pub fn read(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(s) => s.parse().map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Could not read {}: {e}", path.display())),
+        }
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(name.into(), value.into());
+    }
+
+    /// Takes every value given in `other`, replacing any given here
+    pub fn merge(&mut self, other: &Params) {
+        self.0
+            .extend(other.0.iter().map(|(k, v)| (k.clone(), v.clone())));
+    }
+
+    /// Names of every parameter given a value
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    /// The value given for `param`, or its default
+    pub fn get<T>(&self, param: &Param) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let value = self.0.get(param.name).map_or(param.default, String::as_str);
+        value.trim().parse().map_err(|e| {
+            ParseError::new(format!(
+                "Invalid value `{value}` for parameter `{}`: {e}",
+                param.name
+            ))
+        })
+    }
+}
+
+impl Display for Params {
+    /// The same comma separated `name=value` pairs that [`Params::from_str`]
+    /// reads
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, value)) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{name}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Params {
+    type Err = String;
+
+    /// `name=value` pairs separated by commas or newlines
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::default();
+        for pair in s.split([',', '\n']).filter(|p| !p.trim().is_empty()) {
+            let (name, value) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("Expected `name=value`, found `{pair}`"))?;
+            params.insert(name.trim(), value.trim());
+        }
+        Ok(params)
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Params {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut params = Params::default();
+        for (name, value) in iter {
+            params.insert(name, value);
+        }
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZE: Param = Param {
+        name: "size",
+        default: "70",
+        help: "Width of the grid",
+    };
+
+    #[test]
+    fn test_get() {
+        let params = Params::default();
+        assert_eq!(Ok(70), params.get::<usize>(&SIZE));
+
+        let params: Params = "size=6, bytes = 12".parse().unwrap();
+        assert_eq!(Ok(6), params.get::<usize>(&SIZE));
+        assert_eq!(vec!["bytes", "size"], params.names().collect::<Vec<_>>());
+        assert_eq!("bytes=12,size=6", params.to_string());
+        assert_eq!(Ok(params.clone()), "size=6\nbytes=12\n".parse());
+
+        let mut merged = Params::from_iter([("size", "5"), ("width", "3")]);
+        merged.merge(&params);
+        assert_eq!("bytes=12,size=6,width=3", merged.to_string());
+
+        let params = Params::from_iter([("size", "six")]);
+        assert_eq!(
+            "Invalid value `six` for parameter `size`: invalid digit found in string",
+            params.get::<usize>(&SIZE).unwrap_err().to_string()
+        );
+        assert!("size".parse::<Params>().is_err());
+    }
+}
@@ -0,0 +1,283 @@
+use std::{error::Error, fmt::Display, iter, str::FromStr};
+
+/// Puzzle input that a day could not make sense of
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Filled in once the error leaves the day's parser
+    pub day: Option<u8>,
+    /// 1-based line the problem was found on, if it is tied to one
+    pub line: Option<usize>,
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    /// An error about the input as a whole rather than one line
+    pub fn new(reason: impl Display) -> Self {
+        Self {
+            day: None,
+            line: None,
+            text: String::new(),
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn on_day(self, day: u8) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.day, self.line) {
+            (Some(day), Some(line)) => write!(f, "Day {day}, line {line}: ")?,
+            (Some(day), None) => write!(f, "Day {day}: ")?,
+            (None, Some(line)) => write!(f, "Line {line}: ")?,
+            (None, None) => (),
+        }
+        write!(f, "{}", self.reason)?;
+        if !self.text.is_empty() {
+            write!(f, "\n    {}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line of input along with its line number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn error(&self, reason: impl Display) -> ParseError {
+        ParseError {
+            day: None,
+            line: Some(self.number),
+            text: self.text.to_owned(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Splits `s`, some part of this line, around the first `sep`
+    pub fn split_once<'s>(&self, s: &'s str, sep: &str) -> Result<(&'s str, &'s str), ParseError> {
+        s.split_once(sep)
+            .ok_or_else(|| self.error(format!("Expected `{sep}`")))
+    }
+
+    /// Parses `s`, some part of this line, ignoring surrounding whitespace
+    pub fn parse<T>(&self, s: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        s.trim()
+            .parse()
+            .map_err(|e| self.error(format!("Invalid value `{}`: {e}", s.trim())))
+    }
+
+    /// Parses every `sep` separated value in `s`, some part of this line
+    pub fn parse_all<T>(&self, s: &str, sep: &str) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        s.trim()
+            .split(sep)
+            .filter(|s| !s.is_empty())
+            .map(|s| self.parse(s))
+            .collect()
+    }
+
+    /// Parses the part of the line after `label`
+    pub fn field<T>(&self, label: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let value = self
+            .text
+            .trim()
+            .strip_prefix(label)
+            .ok_or_else(|| self.error(format!("Expected `{label}`")))?;
+        self.parse(value)
+    }
+}
+
+/// Numbered lines of an input
+#[derive(Debug, Clone)]
+pub struct Lines<'a> {
+    lines: iter::Enumerate<std::str::Lines<'a>>,
+    last: usize,
+}
+
+impl<'a> Lines<'a> {
+    /// The next line, or an error saying `what` was missing
+    pub fn expect(&mut self, what: &str) -> Result<Line<'a>, ParseError> {
+        let last = self.last;
+        self.next().ok_or_else(|| ParseError {
+            line: Some(last + 1),
+            ..ParseError::new(format!("Missing {what}"))
+        })
+    }
+
+    /// Lines up to the next blank line, which is skipped
+    pub fn section(&mut self) -> Vec<Line<'a>> {
+        self.by_ref()
+            .take_while(|l| !l.text.trim().is_empty())
+            .collect()
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, text) = self.lines.next()?;
+        self.last = i + 1;
+        Some(Line {
+            number: i + 1,
+            text,
+        })
+    }
+}
+
+pub fn lines(input: &str) -> Lines<'_> {
+    Lines {
+        lines: input.lines().enumerate(),
+        last: 0,
+    }
+}
+
+/// Parses each non-blank line with `f`
+pub fn each_line<'a, T>(
+    input: &'a str,
+    f: impl FnMut(Line<'a>) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    lines(input)
+        .filter(|l| !l.text.trim().is_empty())
+        .map(f)
+        .collect()
+}
+
+/// Checks that `input` is a grid: at least one column, every line as wide as
+/// the first, and only made up of the `allowed` characters
+pub fn grid(input: &str, allowed: &str) -> Result<(), ParseError> {
+    let mut width = None;
+    for l in lines(input) {
+        if let Some(c) = l.text.chars().find(|&c| !allowed.contains(c)) {
+            return Err(l.error(format!("Unexpected `{c}`")));
+        }
+        let len = l.text.chars().count();
+        let width = *width.get_or_insert(len);
+        if len != width {
+            return Err(l.error(format!("Expected {width} columns, found {len}")));
+        }
+    }
+    match width {
+        Some(1..) => Ok(()),
+        _ => Err(ParseError::new("The grid is empty")),
+    }
+}
+
+/// Checks that `rows` form a rectangular map with `wall` all the way round,
+/// so that no move from inside it can leave it
+pub fn walled<'a>(rows: impl IntoIterator<Item = Line<'a>>, wall: char) -> Result<(), ParseError> {
+    let rows = rows.into_iter().collect::<Vec<_>>();
+    let (Some(first), Some(last)) = (rows.first(), rows.last()) else {
+        return Err(ParseError::new("The map is empty"));
+    };
+    let width = first.text.chars().count();
+    for l in &rows {
+        let len = l.text.chars().count();
+        if len != width {
+            return Err(l.error(format!("Expected {width} columns, found {len}")));
+        }
+        let edge = l.number == first.number || l.number == last.number;
+        let walled = if edge {
+            l.text.chars().all(|c| c == wall)
+        } else {
+            l.text.starts_with(wall) && l.text.ends_with(wall)
+        };
+        if !walled {
+            return Err(l.error(format!("Expected the map to be surrounded by `{wall}`")));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_errors() {
+        let input = "1,2\n3;4\n";
+        let err = each_line(input, |l| {
+            let (a, b) = l.split_once(l.text, ",")?;
+            Ok((l.parse::<u8>(a)?, l.parse::<u8>(b)?))
+        })
+        .unwrap_err()
+        .on_day(9);
+        assert_eq!(Some(2), err.line);
+        assert_eq!("3;4", err.text);
+        assert_eq!("Day 9, line 2: Expected `,`\n    3;4", err.to_string());
+
+        let err = each_line("5\nx\n", |l| l.parse::<u8>(l.text)).unwrap_err();
+        assert_eq!(
+            "Line 2: Invalid value `x`: invalid digit found in string\n    x",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let mut lines = lines("Register A: 7\n\nProgram: 1,2\n");
+        assert_eq!(
+            Ok(7),
+            lines.expect("register").unwrap().field("Register A:")
+        );
+        assert!(lines.section().is_empty());
+        let program = lines.expect("program").unwrap();
+        let (_, values) = program.split_once(program.text, ":").unwrap();
+        assert_eq!(Ok(vec![1, 2]), program.parse_all::<u8>(values, ","));
+        let err = lines.expect("program").unwrap_err();
+        assert_eq!("Line 4: Missing program", err.to_string());
+    }
+
+    #[test]
+    fn test_grid() {
+        assert!(grid("#.\n.#\n", "#.").is_ok());
+        assert_eq!(Some(2), grid("#.\n.\n", "#.").unwrap_err().line);
+        assert_eq!(
+            "Line 1: Unexpected `x`\n    #x",
+            grid("#x\n..\n", "#.").unwrap_err().to_string()
+        );
+        assert!(grid("", "#.").is_err());
+        assert!(grid("\n\n\n", "#.").is_err());
+    }
+
+    #[test]
+    fn test_walled() {
+        assert!(walled(lines("###\n#.#\n###\n"), '#').is_ok());
+        assert_eq!(
+            Some(2),
+            walled(lines("###\n..#\n###\n"), '#').unwrap_err().line
+        );
+        assert_eq!(
+            Some(3),
+            walled(lines("###\n#.#\n#.#\n"), '#').unwrap_err().line
+        );
+        assert_eq!(
+            Some(2),
+            walled(lines("###\n#\n###\n"), '#').unwrap_err().line
+        );
+        assert!(walled(lines(""), '#').is_err());
+    }
+}
@@ -0,0 +1,205 @@
+//! Pictures of the grid simulations, one [`Frame`] at a time. Days record
+//! frames with a [`Recorder`] in [`crate::Solution::animate`], and `aoc render`
+//! writes them out as images or an animated GIF.
+
+/// A colour for one cell of a frame
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(96, 96, 96);
+    pub const RED: Rgb = Rgb(230, 50, 50);
+    pub const GREEN: Rgb = Rgb(60, 200, 80);
+    pub const BLUE: Rgb = Rgb(60, 110, 230);
+    pub const YELLOW: Rgb = Rgb(240, 210, 60);
+    pub const BROWN: Rgb = Rgb(160, 100, 40);
+
+    /// One of a set of easily told apart colours, such as for numbered files
+    /// or regions. Neighbouring `i` get very different hues.
+    pub fn distinct(i: usize) -> Rgb {
+        // Steps round the colour wheel by the golden angle
+        let hue = (i as f64 * 137.508) % 360.0;
+        let x = 1.0 - ((hue / 60.0) % 2.0 - 1.0).abs();
+        let (r, g, b) = match (hue / 60.0) as u8 {
+            0 => (1.0, x, 0.0),
+            1 => (x, 1.0, 0.0),
+            2 => (0.0, 1.0, x),
+            3 => (0.0, x, 1.0),
+            4 => (x, 0.0, 1.0),
+            _ => (1.0, 0.0, x),
+        };
+        let channel = |c: f64| (55.0 + c * 200.0) as u8;
+        Rgb(channel(r), channel(g), channel(b))
+    }
+
+    /// Between `self` at 0 and `other` at 1
+    pub fn mix(self, other: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+}
+
+/// A picture of a grid with one pixel per cell
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Frame {
+    /// A frame coloured by `cell(x, y)`
+    pub fn new(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> Rgb) -> Self {
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| cell(x, y))
+            .collect();
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// A frame of a picture drawn in text, with `colour` for each character.
+    /// Short lines are padded with the colour of a space.
+    pub fn from_text(text: &str, mut colour: impl FnMut(char) -> Rgb) -> Self {
+        let lines = text.lines().collect::<Vec<_>>();
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let background = colour(' ');
+        let mut pixels = Vec::with_capacity(width * lines.len());
+        for l in &lines {
+            let start = pixels.len();
+            pixels.extend(l.chars().map(&mut colour));
+            pixels.resize(start + width, background);
+        }
+        Self {
+            width,
+            height: lines.len(),
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Every pixel, a row at a time
+    pub fn pixels(&self) -> &[Rgb] {
+        &self.pixels
+    }
+}
+
+/// Collects some of the frames a simulation draws. Drawing is skipped for
+/// frames that won't be kept, so a recorder that keeps none costs next to
+/// nothing in a hot loop.
+#[derive(Debug, Clone, Default)]
+pub struct Recorder {
+    every: usize,
+    limit: usize,
+    offered: usize,
+    frames: Vec<Frame>,
+}
+
+impl Recorder {
+    /// Keeps one of every `every` frames, up to `limit` of them
+    pub fn new(every: usize, limit: usize) -> Self {
+        Self {
+            every: every.max(1),
+            limit,
+            ..Self::default()
+        }
+    }
+
+    /// A recorder that keeps nothing, for runs that only want the answer
+    pub fn off() -> Self {
+        Self::default()
+    }
+
+    /// Offers the frame `draw` makes, drawing it only if it will be kept
+    pub fn frame(&mut self, draw: impl FnOnce() -> Frame) {
+        if self.frames.len() < self.limit && self.offered.is_multiple_of(self.every) {
+            self.frames.push(draw());
+        }
+        self.offered += 1;
+    }
+
+    /// Keeps the frame `draw` makes as the last one, whether or not it would
+    /// be sampled, so the animation ends on the final state
+    pub fn last(&mut self, draw: impl FnOnce() -> Frame) {
+        if self.limit == 0 {
+            return;
+        }
+        if self.frames.len() == self.limit {
+            self.frames.pop();
+        }
+        self.frames.push(draw());
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn into_frames(self) -> Vec<Frame> {
+        self.frames
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame() {
+        let frame = Frame::from_text("#.\n#\n", |c| match c {
+            '#' => Rgb::WHITE,
+            _ => Rgb::BLACK,
+        });
+        assert_eq!((2, 2), (frame.width(), frame.height()));
+        assert_eq!(Rgb::WHITE, frame.get(0, 1));
+        assert_eq!(Rgb::BLACK, frame.get(1, 1));
+        assert_eq!(
+            frame,
+            Frame::new(2, 2, |x, _| if x == 0 { Rgb::WHITE } else { Rgb::BLACK })
+        );
+        assert_eq!(Rgb(128, 128, 128), Rgb::BLACK.mix(Rgb::WHITE, 0.5));
+        assert_ne!(Rgb::distinct(0), Rgb::distinct(1));
+    }
+
+    #[test]
+    fn test_recorder() {
+        let blank = |i| move || Frame::new(1, 1, |_, _| Rgb(i, 0, 0));
+        let mut recorder = Recorder::new(3, 2);
+        let mut drawn = 0;
+        for i in 0..10 {
+            recorder.frame(|| {
+                drawn += 1;
+                blank(i)()
+            });
+        }
+        assert_eq!(2, drawn);
+        recorder.last(blank(9));
+        let reds = recorder.frames().iter().map(|f| f.get(0, 0).0);
+        assert_eq!(vec![0, 9], reds.collect::<Vec<_>>());
+
+        let mut off = Recorder::off();
+        off.frame(|| unreachable!());
+        off.last(|| unreachable!());
+        assert!(off.into_frames().is_empty());
+    }
+}
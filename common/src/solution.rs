@@ -0,0 +1,235 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    generate::{Generated, SeedableRng, SmallRng},
+    memory::{self, Usage},
+    render::Recorder,
+    Param, Params, ParseError,
+};
+
+/// A day's puzzle, split into a shared parse step and the two parts
+pub trait Solution {
+    /// Day of the calendar this solves
+    const DAY: u8;
+
+    /// Puzzle input after parsing. May borrow from the raw input, and is
+    /// shared between the parts when they run concurrently.
+    type Input<'a>: Sync;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    /// Values the parts depend on that differ between the examples and the
+    /// real puzzle, such as grid sizes. Read them in [`Solution::parse_with`].
+    const PARAMS: &'static [Param] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    /// Parses `input` for a run with `params` in place of the defaults
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        _ = params;
+        Self::parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Self::PartOne;
+    fn part_two(input: &Self::Input<'_>) -> Self::PartTwo;
+
+    /// A random input that both parts can solve, for stress tests and
+    /// benchmarks. `size` is the side of a grid or the number of items in a
+    /// list, so the real puzzle is usually somewhere between 100 and 1000.
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated;
+
+    /// Records pictures of `part` as it runs, for days that simulate
+    /// something on a grid. Others record nothing.
+    fn animate(input: &Self::Input<'_>, part: u8, frames: &mut Recorder) {
+        _ = (input, part, frames);
+    }
+}
+
+/// Answers and timings from running some parts of a day
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub parse: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub part: u8,
+    pub answer: String,
+    pub elapsed: Duration,
+    /// Heap usage, if the binary counts allocations
+    pub memory: Usage,
+}
+
+/// A parsed input whose parts can be run without knowing the day's types
+pub trait Parsed: Sync {
+    /// Runs part 1 or 2, panicking for any other part
+    fn part(&self, part: u8) -> String;
+
+    /// Records pictures of part 1 or 2 as it runs
+    fn animate(&self, part: u8, frames: &mut Recorder);
+
+    /// Runs part 1 or 2, timing it and measuring its memory use
+    fn timed(&self, part: u8) -> PartRun {
+        let start = Instant::now();
+        let (answer, memory) = memory::measure(|| self.part(part));
+        PartRun {
+            part,
+            answer,
+            elapsed: start.elapsed(),
+            memory,
+        }
+    }
+}
+
+struct ParsedInput<'a, S: Solution>(S::Input<'a>);
+
+impl<S: Solution> Parsed for ParsedInput<'_, S> {
+    fn part(&self, part: u8) -> String {
+        match part {
+            1 => S::part_one(&self.0).to_string(),
+            2 => S::part_two(&self.0).to_string(),
+            _ => panic!("Part {part} does not exist"),
+        }
+    }
+
+    fn animate(&self, part: u8, frames: &mut Recorder) {
+        S::animate(&self.0, part, frames);
+    }
+}
+
+fn parse<'a, S: Solution + 'static>(
+    input: &'a str,
+    params: &Params,
+) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+    let parsed = S::parse_with(input, params).map_err(|e| e.on_day(S::DAY))?;
+    Ok(Box::new(ParsedInput::<S>(parsed)))
+}
+
+type ParseFn = for<'a> fn(&'a str, &Params) -> Result<Box<dyn Parsed + 'a>, ParseError>;
+
+/// A type-erased [`Solution`], so every day can be kept in one table
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub embedded_input: Option<&'static str>,
+    pub params: &'static [Param],
+    parse: ParseFn,
+    generate: fn(&mut SmallRng, usize) -> Generated,
+}
+
+impl Day {
+    pub const fn new<S: Solution + 'static>(embedded_input: Option<&'static str>) -> Self {
+        Self {
+            day: S::DAY,
+            embedded_input,
+            params: S::PARAMS,
+            parse: parse::<S>,
+            generate: S::generate,
+        }
+    }
+
+    pub fn parse<'a>(
+        &self,
+        input: &'a str,
+        params: &Params,
+    ) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        (self.parse)(input, params)
+    }
+
+    /// The input [`Solution::generate`] makes from `seed`
+    pub fn generate(&self, seed: u64, size: usize) -> Generated {
+        (self.generate)(&mut SmallRng::seed_from_u64(seed), size)
+    }
+
+    /// Parses `input` once, then runs each of `parts` (1 or 2) against it
+    pub fn run(&self, input: &str, params: &Params, parts: &[u8]) -> Result<Run, ParseError> {
+        self.run_with(input, params, |parsed| {
+            parts.iter().map(|&part| parsed.timed(part)).collect()
+        })
+    }
+
+    /// Parses `input` once, then hands it to `run_parts`, which may run the
+    /// parts concurrently
+    pub fn run_with(
+        &self,
+        input: &str,
+        params: &Params,
+        run_parts: impl FnOnce(&dyn Parsed) -> Vec<PartRun>,
+    ) -> Result<Run, ParseError> {
+        let start = Instant::now();
+        let parsed = self.parse(input, params)?;
+        let parse = start.elapsed();
+        Ok(Run {
+            parse,
+            parts: run_parts(parsed.as_ref()),
+        })
+    }
+}
+
+/// Entry point for a day's binary: loads the input named on the command line
+/// and prints both answers. Exits with an error if the input can't be parsed.
+/// Diagnostics are controlled by `$AOC_LOG` and parameters by `$AOC_PARAMS`.
+pub fn main<S: Solution>(embedded_input: Option<&'static str>) {
+    crate::logging::init(0, None);
+    let params = Params::from_env().unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        std::process::exit(2);
+    });
+    let input = crate::input::from_args(S::DAY, embedded_input);
+    let input = S::parse_with(&input, &params).unwrap_or_else(|e| {
+        eprintln!("error: {}", e.on_day(S::DAY));
+        std::process::exit(1);
+    });
+    println!("Part one: {}", S::part_one(&input));
+    println!("Part two: {}", S::part_two(&input));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        type Input<'a> = Vec<&'a str>;
+        type PartOne = usize;
+        type PartTwo = String;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            Ok(input.split(',').collect())
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> usize {
+            input.iter().map(|n| n.parse::<usize>().unwrap()).sum()
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> String {
+            input.concat()
+        }
+
+        fn generate(_: &mut SmallRng, size: usize) -> Generated {
+            vec!["1"; size.max(1)].join(",").into()
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let day = Day::new::<Sum>(None);
+        let run = day.run("1,2,3", &Params::default(), &[2, 1]).unwrap();
+        let answers = run
+            .parts
+            .iter()
+            .map(|p| (p.part, p.answer.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(answers, [(2, "123"), (1, "6")]);
+
+        let generated = day.generate(0, 4);
+        let run = day.run(&generated.input, &generated.params, &[1]).unwrap();
+        assert_eq!("4", run.parts[0].answer);
+    }
+}
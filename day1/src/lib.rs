@@ -0,0 +1,228 @@
+use std::ops::Deref;
+
+use common::{
+    generate::{Generated, Rng, SmallRng},
+    parse::lines,
+    ParseError, Solution,
+};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input<'a> = (Column, Column);
+    type PartOne = u64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one((first, second): &Self::Input<'_>) -> u64 {
+        part_one(first, second)
+    }
+
+    fn part_two((first, second): &Self::Input<'_>) -> i64 {
+        part_two(first, second)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+}
+
+/// A column of location IDs, sorted. Only parsing makes them, so the parts
+/// can rely on the order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Column(Vec<i64>);
+
+impl Deref for Column {
+    type Target = [i64];
+
+    fn deref(&self) -> &[i64] {
+        &self.0
+    }
+}
+
+/// The two columns of location IDs. Columns can be separated by any
+/// whitespace.
+pub fn parse(input: &str) -> Result<(Column, Column), ParseError> {
+    let mut columns = columns(input, Some(2))?.into_iter();
+    match (columns.next(), columns.next()) {
+        (Some(first), Some(second)) => Ok((first, second)),
+        _ => Ok(Default::default()),
+    }
+}
+
+/// Any number of columns of location IDs. Every line must have as many as
+/// the first.
+pub fn parse_columns(input: &str) -> Result<Vec<Column>, ParseError> {
+    columns(input, None)
+}
+
+/// Columns of IDs, `width` of them or else as many as the first line has
+fn columns(input: &str, mut width: Option<usize>) -> Result<Vec<Column>, ParseError> {
+    let mut columns = Vec::<Vec<i64>>::new();
+    for l in lines(input) {
+        let ids = l.text.split_whitespace().collect::<Vec<_>>();
+        if ids.is_empty() {
+            continue;
+        }
+        let width = *width.get_or_insert(ids.len());
+        if ids.len() != width {
+            return Err(l.error(format!("Expected {width} location IDs")));
+        }
+        columns.resize_with(width, Vec::new);
+        for (column, id) in columns.iter_mut().zip(ids) {
+            column.push(l.parse(id)?);
+        }
+    }
+    Ok(columns
+        .into_iter()
+        .map(|mut column| {
+            column.sort_unstable();
+            Column(column)
+        })
+        .collect())
+}
+
+/// `size` pairs of location IDs, with some IDs appearing in both lists
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    let first = (0..size.max(1))
+        .map(|_| rng.gen_range(10_000..100_000))
+        .collect::<Vec<i64>>();
+    let mut input = String::new();
+    for &f in &first {
+        let s = if rng.gen_bool(0.3) {
+            first[rng.gen_range(0..first.len())]
+        } else {
+            rng.gen_range(10_000..100_000)
+        };
+        input.push_str(&format!("{f}   {s}\n"));
+    }
+    input
+}
+
+/// Total distance between the lists, pairing them up smallest first
+pub fn part_one(first: &Column, second: &Column) -> u64 {
+    first
+        .iter()
+        .zip(second.iter())
+        .map(|(a, b)| a.abs_diff(*b))
+        .sum()
+}
+
+/// Each ID in the first list times how often it appears in the second, found
+/// by walking both at once
+pub fn part_two(first: &Column, second: &Column) -> i64 {
+    let (mut i, mut j) = (0, 0);
+    let mut similarity = 0;
+    while i < first.len() {
+        let id = first[i];
+        let repeats = first[i..].iter().take_while(|&&f| f == id).count();
+        j += second[j..].iter().take_while(|&&s| s < id).count();
+        let count = second[j..].iter().take_while(|&&s| s == id).count();
+        similarity += id * (repeats * count) as i64;
+        i += repeats;
+        j += count;
+    }
+    similarity
+}
+
+/// [`part_one`] between every pair of columns, which must be the same length.
+/// Row `i` column `j` compares column `i` with column `j`.
+pub fn distances(columns: &[Column]) -> Vec<Vec<u64>> {
+    matrix(columns, part_one)
+}
+
+/// [`part_two`] between every pair of columns. Row `i` column `j` is how
+/// similar column `i` is to column `j`, which isn't the same both ways.
+pub fn similarities(columns: &[Column]) -> Vec<Vec<i64>> {
+    matrix(columns, part_two)
+}
+
+fn matrix<T>(columns: &[Column], compare: fn(&Column, &Column) -> T) -> Vec<Vec<T>> {
+    columns
+        .iter()
+        .map(|a| columns.iter().map(|b| compare(a, b)).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::differential::Differential;
+
+    use super::*;
+
+    common::example_tests!(Day1);
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("3   4\n4   x\n").unwrap_err();
+        assert_eq!((Some(2), "4   x"), (err.line, err.text.as_str()));
+        let err = parse("3   4\n4 5 6\n").unwrap_err();
+        assert_eq!(Some(2), err.line);
+        let err = parse("3 4 5\n").unwrap_err();
+        assert_eq!("Expected 2 location IDs", err.reason);
+        assert!(parse_columns("1 2 3\n4 5\n").is_err());
+    }
+
+    #[test]
+    fn test_separators() {
+        let (first, second) = parse("3 4\n4\t3\n\n  2 \t 5  \n").unwrap();
+        assert_eq!((&[2, 3, 4][..], &[3, 4, 5][..]), (&*first, &*second));
+        let big = 5_000_000_000;
+        let (first, second) = parse(&format!("{big} 1\n1 {big}\n-{big} {big}")).unwrap();
+        assert_eq!(2 * big as u64, part_one(&first, &second));
+        assert_eq!(2 * big + 1, part_two(&first, &second));
+    }
+
+    #[test]
+    fn test_matrices() {
+        let columns = parse_columns("3 4 3\n4 3 3\n2 5 1\n1 3 9\n3 9 4\n3 3 3\n").unwrap();
+        assert_eq!(3, columns.len());
+        let (first, second) = parse(include_str!("../examples/example.txt")).unwrap();
+        assert_eq!(columns[..2], [first, second]);
+
+        let distances = distances(&columns);
+        assert_eq!(vec![0, 11, 7], distances[0]);
+        assert_eq!(distances[1][0], distances[0][1]);
+        assert_eq!(
+            (0..3).map(|i| distances[i][i]).collect::<Vec<_>>(),
+            [0, 0, 0]
+        );
+        let similarities = similarities(&columns);
+        assert_eq!(31, similarities[0][1]);
+        assert_eq!(vec![32, 40, 41], similarities[2]);
+    }
+
+    #[test]
+    fn test_differential() {
+        Differential::new().check(
+            |rng| {
+                let size = rng.gen_range(1..50);
+                generate(rng, size)
+            },
+            |input| {
+                let pairs = input.lines().map(|l| {
+                    let (f, s) = l.split_once("   ").unwrap();
+                    (f.parse::<i64>().unwrap(), s.parse::<i64>().unwrap())
+                });
+                let (first, second): (Vec<_>, Vec<_>) = pairs.unzip();
+                first
+                    .iter()
+                    .map(|f| f * second.iter().filter(|s| *s == f).count() as i64)
+                    .sum::<i64>()
+            },
+            |input| {
+                let (first, second) = parse(input).unwrap();
+                part_two(&first, &second)
+            },
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::HashSet;
+
+use common::{
+    generate::{self, Generated, Rng, SmallRng},
+    parse, ParseError, Solution,
+};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+type Num = usize;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input<'a> = Vec<Vec<Num>>;
+    type PartOne = Num;
+    type PartTwo = Num;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Num {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Num {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+}
+
+/// A map `size` squares wide of random heights, crossed by trails that climb
+/// from 0 to 9 one step at a time
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    let size = size.max(1);
+    let mut map = (0..size)
+        .map(|_| (0..size).map(|_| rng.gen_range(0..=9)).collect())
+        .collect::<Vec<Vec<u8>>>();
+    for _ in 0..(size * size / 20).max(1) {
+        let (mut x, mut y) = (rng.gen_range(0..size), rng.gen_range(0..size));
+        for height in 0..=9 {
+            map[y][x] = height;
+            match rng.gen_range(0..4) {
+                0 => x = (x + 1).min(size - 1),
+                1 => x = x.saturating_sub(1),
+                2 => y = (y + 1).min(size - 1),
+                _ => y = y.saturating_sub(1),
+            }
+        }
+    }
+    generate::grid(size, size, |x, y| char::from(b'0' + map[y][x]))
+}
+
+fn score(trailhead: (Num, Num), map: &[Vec<Num>]) -> Num {
+    let mut paths = vec![vec![trailhead]];
+    // look for h next to path.last(). If more than one, clone and add to end of paths
+    for h in 1..10 {
+        let mut visited_set = HashSet::new();
+        paths = paths
+            .into_iter()
+            .flat_map(|v| {
+                let &(x, y) = v.last().expect("Known to have min one element");
+                let mut new_vec = Vec::new();
+                // Up
+                if y.checked_sub(1).map(|y| map[y][x]) == Some(h) {
+                    let pt = (x, y - 1);
+                    if visited_set.insert(pt) {
+                        let mut p = v.clone();
+                        p.push(pt);
+                        new_vec.push(p);
+                    }
+                }
+                // Down
+                if map.get(y + 1).map(|row| row[x]) == Some(h) {
+                    let pt = (x, y + 1);
+                    if visited_set.insert(pt) {
+                        let mut p = v.clone();
+                        p.push(pt);
+                        new_vec.push(p);
+                    }
+                }
+                // Left
+                if x.checked_sub(1).map(|x| map[y][x]) == Some(h) {
+                    let pt = (x - 1, y);
+                    if visited_set.insert(pt) {
+                        let mut p = v.clone();
+                        p.push(pt);
+                        new_vec.push(p);
+                    }
+                }
+                // Right
+                if map[y].get(x + 1) == Some(&h) {
+                    let pt = (x + 1, y);
+                    if visited_set.insert(pt) {
+                        let mut p = v.clone();
+                        p.push(pt);
+                        new_vec.push(p);
+                    }
+                }
+
+                new_vec
+            })
+            .collect()
+    }
+    paths.len()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<Num>>, ParseError> {
+    parse::grid(input, "0123456789")?;
+    Ok(input
+        .lines()
+        .map(|l| l.bytes().map(|n| (n - b'0') as Num).collect::<Vec<_>>())
+        .collect())
+}
+
+pub fn part_one(topography: &[Vec<Num>]) -> Num {
+    let trailheads = topography.iter().enumerate().flat_map(|(y, v)| {
+        v.iter()
+            .enumerate()
+            .filter(|(_, h)| **h == 0)
+            .map(move |(x, _)| (x, y))
+    });
+
+    // Lose some efficiency for overlapping paths
+    // Can potentially improve using a map (x, y) -> known score
+    trailheads.into_iter().map(|th| score(th, topography)).sum()
+}
+
+// Same as score, just without visited set
+fn rating(trailhead: (Num, Num), map: &[Vec<Num>]) -> Num {
+    let mut paths = vec![vec![trailhead]];
+    for h in 1..10 {
+        paths = paths
+            .into_iter()
+            .flat_map(|v| {
+                let &(x, y) = v.last().expect("Known to have min one element");
+                let mut new_vec = Vec::new();
+                // Up
+                if y.checked_sub(1).map(|y| map[y][x]) == Some(h) {
+                    let pt = (x, y - 1);
+                    let mut p = v.clone();
+                    p.push(pt);
+                    new_vec.push(p);
+                }
+                // Down
+                if map.get(y + 1).map(|row| row[x]) == Some(h) {
+                    let pt = (x, y + 1);
+                    let mut p = v.clone();
+                    p.push(pt);
+                    new_vec.push(p);
+                }
+                // Left
+                if x.checked_sub(1).map(|x| map[y][x]) == Some(h) {
+                    let pt = (x - 1, y);
+                    let mut p = v.clone();
+                    p.push(pt);
+                    new_vec.push(p);
+                }
+                // Right
+                if map[y].get(x + 1) == Some(&h) {
+                    let pt = (x + 1, y);
+                    let mut p = v.clone();
+                    p.push(pt);
+                    new_vec.push(p);
+                }
+
+                new_vec
+            })
+            .collect()
+    }
+    paths.len()
+}
+
+pub fn part_two(topography: &[Vec<Num>]) -> Num {
+    let trailheads = topography.iter().enumerate().flat_map(|(y, v)| {
+        v.iter()
+            .enumerate()
+            .filter(|(_, h)| **h == 0)
+            .map(move |(x, _)| (x, y))
+    });
+
+    // Lose some efficiency for overlapping paths
+    // Can potentially improve using a map (x, y) -> known score
+    trailheads
+        .into_iter()
+        .map(|th| rating(th, topography))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day10);
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use common::{
+    generate::{Generated, Rng, SmallRng},
+    parse::lines,
+    Param, Params, ParseError, Solution,
+};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+type Num = usize;
+
+pub const BLINKS_ONE: Param = Param {
+    name: "blinks_one",
+    default: "25",
+    help: "Times the stones blink in part one",
+};
+pub const BLINKS_TWO: Param = Param {
+    name: "blinks_two",
+    default: "75",
+    help: "Times the stones blink in part two",
+};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    /// Stones, and how many times they blink in each part
+    type Input<'a> = (Vec<Num>, [Num; 2]);
+    type PartOne = Num;
+    type PartTwo = Num;
+
+    const PARAMS: &'static [Param] = &[BLINKS_ONE, BLINKS_TWO];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        let blinks = [params.get(&BLINKS_ONE)?, params.get(&BLINKS_TWO)?];
+        Ok((parse(input)?, blinks))
+    }
+
+    fn part_one((stones, blinks): &Self::Input<'_>) -> Num {
+        step_n(stones, blinks[0])
+    }
+
+    fn part_two((stones, blinks): &Self::Input<'_>) -> Num {
+        step_n(stones, blinks[1])
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Num>, ParseError> {
+    let stones = lines(input.trim()).expect("stones")?;
+    stones.parse_all(stones.text, " ")
+}
+
+/// A line of `size` stones with up to 7 digit numbers
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    let stones = (0..size.max(1))
+        .map(|_| {
+            let digits = rng.gen_range(1..=7);
+            rng.gen_range(0..10_usize.pow(digits)).to_string()
+        })
+        .collect::<Vec<_>>();
+    stones.join(" ") + "\n"
+}
+
+pub fn step_n(stones: &[Num], n: usize) -> Num {
+    let mut cache = HashMap::new();
+    stones.iter().map(|&s| count(s, n, &mut cache)).sum()
+}
+
+fn count(n: Num, steps: Num, cache: &mut HashMap<(Num, Num), Num>) -> Num {
+    if steps == 0 {
+        return 1;
+    }
+    if let Some(v) = cache.get(&(n, steps)) {
+        return *v;
+    }
+    if n == 0 {
+        let c = count(1, steps - 1, cache);
+        cache.insert((n, steps), c);
+        return c;
+    }
+    let cd = count_digits(n);
+    if cd.is_multiple_of(2) {
+        let div = (10 as Num).pow((cd / 2) as u32);
+        let c = count(n / div, steps - 1, cache) + count(n % div, steps - 1, cache);
+        cache.insert((n, steps), c);
+        c
+    } else {
+        let c = count(n * 2024, steps - 1, cache);
+        cache.insert((n, steps), c);
+        c
+    }
+}
+
+fn count_digits(n: Num) -> Num {
+    n.ilog10() as Num + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day11);
+}
@@ -0,0 +1,216 @@
+use common::{
+    generate::{Generated, Rng, SmallRng},
+    parse::{lines, Line, Lines},
+    ParseError, Solution,
+};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input<'a> = Vec<ArcadeMachine>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> usize {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ArcadeMachine {
+    pub a: (usize, usize),
+    pub b: (usize, usize),
+    pub prize: (usize, usize),
+}
+
+impl ArcadeMachine {
+    // Algrebra! Only ever 1 solution, unless a = b * n (which doesn't happen
+    // in my input)
+    pub fn path(&self) -> Option<(usize, usize)> {
+        // Wide enough for the products of any coordinates parse accepts
+        let (a, b, prize) = (self.a, self.b, self.prize);
+        let [ax, ay, bx, by, px, py] = [a.0, a.1, b.0, b.1, prize.0, prize.1].map(|n| n as i128);
+        let b_dividend = py * ax - px * ay;
+        let b_divisor = ax * by - bx * ay;
+        if b_dividend % b_divisor != 0 {
+            return None;
+        }
+        let b_presses = b_dividend / b_divisor;
+
+        // A may only move along one axis
+        let (a_move, b_move, prize) = if ax != 0 { (ax, bx, px) } else { (ay, by, py) };
+        let a_dividend = prize - b_presses * b_move;
+        if a_dividend % a_move != 0 {
+            return None;
+        }
+        let a_presses = a_dividend / a_move;
+
+        usize::try_from(a_presses)
+            .ok()
+            .zip(usize::try_from(b_presses).ok())
+    }
+}
+
+impl ArcadeMachine {
+    fn parse(lines: &mut Lines) -> Result<Self, ParseError> {
+        let l = lines.expect("line for Button A")?;
+        let a = parse_button(l)?;
+        let b = parse_button(lines.expect("line for Button B")?)?;
+        // `path` relies on there being at most one way to win
+        if a.0 * b.1 == a.1 * b.0 {
+            return Err(l.error("Buttons A and B move in the same direction"));
+        }
+        let prize = parse_button(lines.expect("line for prize")?)?;
+        Ok(Self { a, b, prize })
+    }
+}
+
+fn parse_button(l: Line) -> Result<(usize, usize), ParseError> {
+    let (_, coords) = l.split_once(l.text, "X")?;
+    let (x, y) = l.split_once(coords, ",")?;
+    let (_, y) = l.split_once(y, "Y")?;
+    let coord = |c: &str| {
+        l.parse::<u32>(c.trim_start_matches(['+', '=']))
+            .map(|n| n as usize)
+    };
+    Ok((coord(x)?, coord(y)?))
+}
+
+/// `size` claw machines. About half of them can win their prize in at most
+/// 100 presses of each button.
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    let mut machines = Vec::new();
+    while machines.len() < size.max(1) {
+        let mut button = || (rng.gen_range(1..100), rng.gen_range(1..100));
+        let (a, b): ((usize, usize), (usize, usize)) = (button(), button());
+        // Like the puzzle's, no machine has buttons moving the same way
+        if a.0 * b.1 == a.1 * b.0 {
+            continue;
+        }
+        let prize = if rng.gen_bool(0.5) {
+            let (na, nb) = (rng.gen_range(0..=100), rng.gen_range(0..=100));
+            (a.0 * na + b.0 * nb, a.1 * na + b.1 * nb)
+        } else {
+            (rng.gen_range(0..=10_000), rng.gen_range(0..=10_000))
+        };
+        machines.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        ));
+    }
+    machines.join("\n\n") + "\n"
+}
+
+pub fn parse(input: &str) -> Result<Vec<ArcadeMachine>, ParseError> {
+    let mut lines = lines(input);
+    let mut machines = vec![ArcadeMachine::parse(&mut lines)?];
+    while let Some(l) = lines.next() {
+        if !l.text.trim().is_empty() {
+            return Err(l.error("Expected a blank line between machines"));
+        }
+        machines.push(ArcadeMachine::parse(&mut lines)?);
+    }
+    Ok(machines)
+}
+
+pub fn part_one(machines: &[ArcadeMachine]) -> usize {
+    machines
+        .iter()
+        .filter_map(|a| a.path())
+        .map(|(ac, bc)| ac * 3 + bc)
+        .sum()
+}
+
+pub fn part_two(machines: &[ArcadeMachine]) -> usize {
+    const ERROR: usize = 10_000_000_000_000;
+    machines
+        .iter()
+        .map(|am| {
+            let mut am = am.clone();
+            am.prize.0 += ERROR;
+            am.prize.1 += ERROR;
+            am
+        })
+        .filter_map(|a| a.path())
+        .map(|(ac, bc)| ac * 3 + bc)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{differential::Differential, generate::Rng};
+
+    use super::*;
+
+    common::example_tests!(Day13);
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("Button A: X+94, Y+34\nButton B: X+22 Y+67\n").unwrap_err();
+        assert_eq!(Some(2), err.line);
+        let err = parse("Button A: X+94, Y+34\nButton B: X+22, Y+67\n").unwrap_err();
+        assert_eq!("Line 3: Missing line for prize", err.to_string());
+        let err = parse("Button A: X+2, Y+4\nButton B: X+1, Y+2\nPrize: X=3, Y=6\n").unwrap_err();
+        assert_eq!(Some(1), err.line);
+    }
+
+    #[test]
+    fn test_vertical_button() {
+        let machines = parse("Button A: X+0, Y+4\nButton B: X+3, Y+1\nPrize: X=6, Y=14").unwrap();
+        assert_eq!(Some((3, 2)), machines[0].path());
+    }
+
+    /// Tries every number of A presses, as `path` did before the algebra
+    fn path_by_search(m: &ArcadeMachine) -> Option<(usize, usize)> {
+        for a in 0.. {
+            if m.a.0 * a > m.prize.0 || m.a.1 * a > m.prize.1 {
+                break;
+            }
+            let rem = (m.prize.0 - a * m.a.0, m.prize.1 - a * m.a.1);
+            if rem.0.is_multiple_of(m.b.0)
+                && rem.1.is_multiple_of(m.b.1)
+                && rem.0 / m.b.0 == rem.1 / m.b.1
+            {
+                return Some((a, rem.0 / m.b.0));
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn test_differential() {
+        Differential::new().check(
+            |rng| {
+                let size = rng.gen_range(1..=5);
+                generate(rng, size)
+            },
+            |input| {
+                let machines = parse(input).unwrap();
+                machines
+                    .iter()
+                    .filter_map(path_by_search)
+                    .map(|(ac, bc)| ac * 3 + bc)
+                    .sum::<usize>()
+            },
+            |input| part_one(&parse(input).unwrap()),
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use common::{
+    generate::{Generated, Rng, SmallRng},
+    parse::each_line,
+    render::{Frame, Recorder, Rgb},
+    Param, Params, ParseError, Solution,
+};
+use log::debug;
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+pub const WIDTH: Param = Param {
+    name: "width",
+    default: "101",
+    help: "Width of the space the robots move in",
+};
+pub const HEIGHT: Param = Param {
+    name: "height",
+    default: "103",
+    help: "Height of the space the robots move in",
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    /// Robots, and the width and height of their space
+    type Input<'a> = (Vec<Robot>, i32, i32);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    const PARAMS: &'static [Param] = &[WIDTH, HEIGHT];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        let (width, height) = (params.get(&WIDTH)?, params.get(&HEIGHT)?);
+        if width <= 0 || height <= 0 {
+            return Err(ParseError::new(
+                "The space must have a positive width and height",
+            ));
+        }
+        Ok((parse(input)?, width, height))
+    }
+
+    fn part_one((robots, width, height): &Self::Input<'_>) -> usize {
+        part_one(robots, *width, *height)
+    }
+
+    fn part_two((robots, width, height): &Self::Input<'_>) -> usize {
+        part_two(robots, *width, *height)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size)
+    }
+
+    fn animate((robots, width, height): &Self::Input<'_>, part: u8, frames: &mut Recorder) {
+        match part {
+            1 => _ = part_one_with(robots, *width, *height, frames),
+            _ => _ = part_two_with(robots, *width, *height, frames),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    pos: (i32, i32),
+    vel: (i32, i32),
+}
+
+impl Robot {
+    fn tick(&mut self, width: i32, height: i32) {
+        self.pos.0 += self.vel.0;
+        if self.pos.0 < 0 {
+            self.pos.0 += width;
+        }
+        if self.pos.0 >= width {
+            self.pos.0 -= width;
+        }
+
+        self.pos.1 += self.vel.1;
+        if self.pos.1 < 0 {
+            self.pos.1 += height;
+        }
+        if self.pos.1 >= height {
+            self.pos.1 -= height;
+        }
+    }
+}
+
+/// `size` robots in a space about `size` tiles wide, with odd sides so that
+/// robots in the middle row and column are in no quadrant
+pub fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+    let (width, height) = ((size | 1).max(3) as i32, (size | 1).max(3) as i32 + 2);
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        input.push_str(&format!(
+            "p={},{} v={},{}\n",
+            rng.gen_range(0..width),
+            rng.gen_range(0..height),
+            rng.gen_range(1 - width..width),
+            rng.gen_range(1 - height..height),
+        ));
+    }
+    Generated::new(input)
+        .with(&WIDTH, width)
+        .with(&HEIGHT, height)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Robot>, ParseError> {
+    each_line(input, |l| {
+        let pair = |s| {
+            let (x, y) = l.split_once(s, ",")?;
+            Ok::<_, ParseError>((l.parse(x)?, l.parse(y)?))
+        };
+        let (_, robot) = l.split_once(l.text, "p=")?;
+        let (pos, vel) = l.split_once(robot, " v=")?;
+        Ok(Robot {
+            pos: pair(pos)?,
+            vel: pair(vel)?,
+        })
+    })
+}
+
+pub fn part_one(robots: &[Robot], width: i32, height: i32) -> usize {
+    part_one_with(robots, width, height, &mut Recorder::off())
+}
+
+fn part_one_with(robots: &[Robot], width: i32, height: i32, frames: &mut Recorder) -> usize {
+    let mut robots = robots.to_vec();
+    frames.frame(|| picture(&robots, width, height));
+    for _ in 0..100 {
+        for r in robots.iter_mut() {
+            r.tick(width, height);
+        }
+        frames.frame(|| picture(&robots, width, height));
+    }
+    frames.last(|| picture(&robots, width, height));
+
+    let quadrant_counts = quadrants(&robots, width, height);
+    debug!("Quadrants: {quadrant_counts:?}");
+    quadrant_counts
+        .into_iter()
+        .reduce(|factor, next| factor * next)
+        .expect("quadrant_counts has 4 elements")
+}
+
+/// Picture of the robots, with the number on each tile
+fn draw_robots(rbts: &[Robot], width: i32, height: i32) -> String {
+    let mut picture = String::new();
+    for y in 0..height {
+        picture.push('\n');
+        for x in 0..width {
+            let c = rbts.iter().filter(|r| r.pos == (x, y)).count();
+            if c != 0 {
+                picture += &c.to_string();
+            } else {
+                picture.push('.');
+            }
+        }
+    }
+    picture
+}
+
+/// Robots in green, brighter where they overlap
+fn picture(rbts: &[Robot], width: i32, height: i32) -> Frame {
+    let mut counts = vec![0; (width * height) as usize];
+    for r in rbts {
+        counts[(r.pos.1 * width + r.pos.0) as usize] += 1;
+    }
+    Frame::new(width as usize, height as usize, |x, y| {
+        match counts[y * width as usize + x] {
+            0 => Rgb::BLACK,
+            n => Rgb::BLACK.mix(Rgb::GREEN, 0.5 + n as f64 / 4.0),
+        }
+    })
+}
+
+fn quadrants(rbts: &[Robot], width: i32, height: i32) -> [usize; 4] {
+    rbts.iter()
+        .filter_map(|r| {
+            let x = if r.pos.0 > width / 2 { 1 } else { 0 };
+            if width % 2 == 1 && r.pos.0 == width / 2 {
+                return None;
+            }
+            let y = if r.pos.1 > height / 2 { 2 } else { 0 };
+            if height % 2 == 1 && r.pos.1 == height / 2 {
+                // might be wrong, but only odd sized grids here
+                return None;
+            }
+            Some(x + y)
+        })
+        .fold([0usize; 4], |mut ql, q| {
+            ql[q] += 1;
+            ql
+        })
+}
+
+pub fn part_two(robots: &[Robot], width: i32, height: i32) -> usize {
+    part_two_with(robots, width, height, &mut Recorder::off())
+}
+
+/// Ends on the picture of the robots when they are least spread out
+fn part_two_with(robots: &[Robot], width: i32, height: i32, frames: &mut Recorder) -> usize {
+    let mut robots = robots.to_vec();
+    let mut min_danger = usize::MAX;
+    let mut min_danger_robots = Vec::new();
+    let mut min_danger_time = 0;
+    for s in 1..10_000 {
+        for r in robots.iter_mut() {
+            r.tick(width, height);
+        }
+        frames.frame(|| picture(&robots, width, height));
+        let qc = quadrants(&robots, width, height);
+        let danger = qc
+            .into_iter()
+            .reduce(|factor, next| factor * next)
+            .expect("quadrant_counts has 4 elements");
+        if danger < min_danger {
+            min_danger = danger;
+            min_danger_robots = robots.clone();
+            min_danger_time = s;
+        }
+    }
+
+    debug!(
+        "After {min_danger_time} seconds:{}",
+        draw_robots(&min_danger_robots, width, height)
+    );
+    frames.last(|| picture(&min_danger_robots, width, height));
+    min_danger_time
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day14);
+}
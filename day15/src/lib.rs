@@ -0,0 +1,279 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use common::{
+    generate::{self, Generated, Rng, SmallRng},
+    parse::{lines, walled},
+    render::{Frame, Recorder, Rgb},
+    ParseError, Solution,
+};
+use grid::{Dir, Point};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+type Num = usize;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input<'a> = Warehouse<'a>;
+    type PartOne = Num;
+    type PartTwo = Num;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Num {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Num {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+
+    fn animate(input: &Self::Input<'_>, part: u8, frames: &mut Recorder) {
+        match part {
+            1 => _ = part_one_with(input, frames),
+            _ => _ = part_two_with(input, frames),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Obj {
+    Wall,
+    Box,
+}
+
+/// Rows of the map and the robot's moves
+#[derive(Debug, Clone)]
+pub struct Warehouse<'a> {
+    map: Vec<&'a str>,
+    moves: Vec<Dir>,
+}
+
+/// A walled warehouse `size` tiles wide, scattered with walls and boxes, and
+/// `size` lines of moves
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    let size = size.max(3);
+    let robot = (rng.gen_range(1..size - 1), rng.gen_range(1..size - 1));
+    let mut input = generate::grid(size, size, |x, y| {
+        if x == 0 || y == 0 || x == size - 1 || y == size - 1 {
+            '#'
+        } else if (x, y) == robot {
+            '@'
+        } else {
+            match rng.gen_range(0..10) {
+                0 => '#',
+                1..=3 => 'O',
+                _ => '.',
+            }
+        }
+    });
+    input.push('\n');
+    for _ in 0..size {
+        input.extend((0..70).map(|_| ['^', '>', 'v', '<'][rng.gen_range(0..4)]));
+        input.push('\n');
+    }
+    input
+}
+
+pub fn parse(input: &str) -> Result<Warehouse<'_>, ParseError> {
+    let mut lines = lines(input);
+    let map = lines.section();
+    for l in &map {
+        if let Some(c) = l.text.chars().find(|&c| !"#.O@".contains(c)) {
+            return Err(l.error(format!("Unexpected `{c}` in the map")));
+        }
+    }
+    walled(map.iter().copied(), '#')?;
+    if map
+        .iter()
+        .map(|l| l.text.matches('@').count())
+        .sum::<usize>()
+        != 1
+    {
+        return Err(ParseError::new("Expected exactly one robot in the map"));
+    }
+    let mut moves = Vec::new();
+    for l in lines {
+        for c in l.text.trim().chars() {
+            moves
+                .push(Dir::from_arrow(c).ok_or_else(|| l.error(format!("Unexpected move `{c}`")))?);
+        }
+    }
+    Ok(Warehouse {
+        map: map.into_iter().map(|l| l.text).collect(),
+        moves,
+    })
+}
+
+pub fn part_one(warehouse: &Warehouse) -> Num {
+    part_one_with(warehouse, &mut Recorder::off())
+}
+
+fn part_one_with(warehouse: &Warehouse, frames: &mut Recorder) -> Num {
+    let mut robot = Point::default();
+    let mut map: HashMap<Point, Obj> = warehouse
+        .map
+        .iter()
+        .enumerate()
+        .flat_map(|(y, l)| l.chars().enumerate().map(move |(x, c)| (x, y, c)))
+        .filter_map(|(x, y, c)| match c {
+            '#' => Some((Point::new(x, y), Obj::Wall)),
+            'O' => Some((Point::new(x, y), Obj::Box)),
+            '@' => {
+                robot = Point::new(x, y);
+                None
+            }
+            _ => None,
+        })
+        .collect();
+    assert!(robot != Point::default());
+    for &m in &warehouse.moves {
+        frames.frame(|| picture(warehouse, &map, robot, 1));
+        let mut pt = m.offset(robot);
+        while map.get(&pt) == Some(&Obj::Box) {
+            pt = m.offset(pt);
+        }
+        let may_move = !map.contains_key(&pt);
+        if !may_move {
+            continue;
+        }
+        robot = m.offset(robot);
+        while let Some(obj) = map.remove(&m.reverse().offset(pt)) {
+            map.insert(pt, obj);
+            pt = m.reverse().offset(pt);
+        }
+    }
+    frames.last(|| picture(warehouse, &map, robot, 1));
+
+    map.into_iter()
+        .filter_map(|(Point { x, y }, o)| {
+            if o == Obj::Box {
+                Some(100 * y + x)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+pub fn part_two(warehouse: &Warehouse) -> Num {
+    part_two_with(warehouse, &mut Recorder::off())
+}
+
+fn part_two_with(warehouse: &Warehouse, frames: &mut Recorder) -> Num {
+    let mut robot = Point::default();
+    // Boxes and walls are indicated by Objs in their left space
+    let mut map: HashMap<Point, Obj> = warehouse
+        .map
+        .iter()
+        .enumerate()
+        .flat_map(|(y, l)| l.chars().enumerate().map(move |(x, c)| (x * 2, y, c)))
+        .filter_map(|(x, y, c)| match c {
+            '#' => Some((Point::new(x, y), Obj::Wall)),
+            'O' => Some((Point::new(x, y), Obj::Box)),
+            '@' => {
+                robot = Point::new(x, y);
+                None
+            }
+            _ => None,
+        })
+        .collect();
+    'outer: for &m in &warehouse.moves {
+        frames.frame(|| picture(warehouse, &map, robot, 2));
+        let mut pts_to_check = BTreeSet::new();
+        pts_to_check.insert(m.offset(robot)); // pushing on this square
+        pts_to_check.insert(Dir::West.offset(m.offset(robot))); // If this is a Box, it is in the adjacent square too
+        let mut checked = HashSet::new();
+
+        // Should this be first, last? Does it matter
+        while let Some(pt) = pts_to_check.pop_first() {
+            checked.insert(pt);
+            match map.get(&pt) {
+                None => (), // Nothing to worry about, keep checking
+                Some(Obj::Box) => {
+                    let new_pt = m.offset(pt);
+                    if !checked.contains(&new_pt) {
+                        pts_to_check.insert(new_pt);
+                    }
+                    if !checked.contains(&Dir::East.offset(new_pt)) {
+                        // Right since this box extends to the right from the label
+                        pts_to_check.insert(Dir::East.offset(new_pt));
+                    }
+                    if !checked.contains(&Dir::West.offset(new_pt)) {
+                        // Left since a box/wall here extends above this point
+                        pts_to_check.insert(Dir::West.offset(new_pt));
+                    }
+                }
+                Some(Obj::Wall) => continue 'outer,
+            }
+        }
+
+        robot = m.offset(robot);
+        push(&mut map, robot, m);
+        push(&mut map, Dir::West.offset(robot), m);
+    }
+    frames.last(|| picture(warehouse, &map, robot, 2));
+    map.into_iter()
+        .filter_map(|(Point { x, y }, o)| {
+            if o == Obj::Box {
+                Some(100 * y + x)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+/// The robot in red and boxes in brown, in a warehouse `scale` times as wide
+/// as the map, where each object is that wide too
+fn picture(warehouse: &Warehouse, map: &HashMap<Point, Obj>, robot: Point, scale: usize) -> Frame {
+    let width = warehouse.map.iter().map(|l| l.len()).max().unwrap_or(0) * scale;
+    Frame::new(width, warehouse.map.len(), |x, y| {
+        // Objects are stored by their leftmost tile
+        let object = (0..scale)
+            .filter_map(|dx| x.checked_sub(dx))
+            .find_map(|x| map.get(&Point::new(x, y)));
+        match object {
+            _ if Point::new(x, y) == robot => Rgb::RED,
+            Some(Obj::Wall) => Rgb::GREY,
+            Some(Obj::Box) => Rgb::BROWN,
+            None => Rgb::BLACK,
+        }
+    })
+}
+
+fn push(map: &mut HashMap<Point, Obj>, pt: Point, dir: Dir) {
+    let Some(obj) = map.remove(&pt) else {
+        return;
+    };
+    let dest = dir.offset(pt);
+    push(map, Dir::West.offset(dest), dir);
+    push(map, dest, dir);
+    push(map, Dir::East.offset(dest), dir);
+    map.insert(dest, obj);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day15);
+
+    #[test]
+    fn test_parse_error() {
+        assert!(parse("@.\n..\n\n<\n").is_err());
+        let err = parse("####\n#@.#\n#..\n####\n\n<\n").unwrap_err();
+        assert_eq!(Some(3), err.line);
+    }
+}
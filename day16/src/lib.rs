@@ -0,0 +1,343 @@
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use common::{
+    generate::{self, Generated, Rng, SmallRng},
+    parse,
+    render::{Frame, Recorder, Rgb},
+    ParseError, Solution,
+};
+use grid::{Dir, Grid, Point};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+type Num = usize;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input<'a> = Maze;
+    type PartOne = Num;
+    type PartTwo = Num;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Num {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Num {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+
+    fn animate(input: &Self::Input<'_>, part: u8, frames: &mut Recorder) {
+        match part {
+            1 => _ = part_one_with(input, frames),
+            _ => _ = part_two_with(input, frames),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Path {
+    steps: Vec<(Point, Dir)>,
+    facing: Dir,
+    location: Point,
+    score: Num,
+}
+
+impl Path {
+    // Possible moves filtered by open spaces
+    // Can loop, but that should be broken by heap
+    fn options(&self, walls: &Grid<bool>) -> impl IntoIterator<Item = Path> {
+        let forward = if !walls[self.facing.offset(self.location)] {
+            let mut p = self.clone();
+            p.location = p.facing.offset(p.location);
+            p.score += 1;
+            p.steps.push((p.location, p.facing));
+            Some(p)
+        } else {
+            None
+        };
+        let left = if !walls[self.facing.left().offset(self.location)] {
+            let mut p = self.clone();
+            p.facing = p.facing.left();
+            p.location = p.facing.offset(p.location);
+            p.score += 1001;
+            p.steps.push((p.location, p.facing));
+            Some(p)
+        } else {
+            None
+        };
+        let right = if !walls[self.facing.right().offset(self.location)] {
+            let mut p = self.clone();
+            p.facing = p.facing.right();
+            p.location = p.facing.offset(p.location);
+            p.score += 1001;
+            p.steps.push((p.location, p.facing));
+            Some(p)
+        } else {
+            None
+        };
+
+        forward.into_iter().chain(left).chain(right)
+    }
+}
+
+impl PartialOrd for Path {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Path {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.score.cmp(&other.score).reverse()
+    }
+}
+
+/// Walls, start and end of the maze
+pub type Maze = (Grid<bool>, Point, Point);
+
+pub fn parse(input: &str) -> Result<Maze, ParseError> {
+    parse::grid(input, "#.SE")?;
+    parse::walled(parse::lines(input), '#')?;
+    let mut start = None;
+    let mut end = None;
+    let map = Grid::parse(input, |pt, c| {
+        match c {
+            'S' => start = Some(pt),
+            'E' => end = Some(pt),
+            _ => (),
+        }
+        c == '#'
+    });
+
+    Ok((
+        map,
+        start.ok_or_else(|| ParseError::new("No start in the maze"))?,
+        end.ok_or_else(|| ParseError::new("No end in the maze"))?,
+    ))
+}
+
+/// A walled maze `size` tiles wide with the start in the bottom left and the
+/// end in the top right, like the puzzle's
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    let size = size.max(4);
+    let (start, end) = (Point::new(1, size - 2), Point::new(size - 2, 1));
+    loop {
+        let mut walls = Grid::new(size, size, false);
+        for pt in walls.points().collect::<Vec<_>>() {
+            let edge = pt.x == 0 || pt.y == 0 || pt.x == size - 1 || pt.y == size - 1;
+            walls[pt] = edge || (pt != start && pt != end && rng.gen_bool(0.3));
+        }
+        // Keep it if the end can be reached
+        let mut seen = HashSet::from([start]);
+        let mut todo = vec![start];
+        while let Some(pt) = todo.pop() {
+            todo.extend(pt.neighbors().filter(|&n| !walls[n] && seen.insert(n)));
+        }
+        if seen.contains(&end) {
+            return generate::grid(size, size, |x, y| match Point::new(x, y) {
+                pt if pt == start => 'S',
+                pt if pt == end => 'E',
+                pt if walls[pt] => '#',
+                _ => '.',
+            });
+        }
+    }
+}
+
+pub fn part_one(maze: &Maze) -> Num {
+    part_one_with(maze, &mut Recorder::off())
+}
+
+/// Ends on the best path
+fn part_one_with((map, start, end): &Maze, frames: &mut Recorder) -> Num {
+    let (start, end) = (*start, *end);
+
+    let mut heap = BinaryHeap::<Path>::new();
+    heap.push(Path {
+        steps: vec![(start, Dir::East)],
+        facing: Dir::East,
+        location: start,
+        score: 0,
+    });
+    let mut visited = HashSet::<(Point, Dir)>::new();
+    while let Some(p) = heap.pop() {
+        frames.frame(|| picture(map, visited.iter().map(|(pt, _)| pt), []));
+        if p.location == end {
+            let path = p.steps.iter().map(|(pt, _)| pt);
+            frames.last(|| picture(map, visited.iter().map(|(pt, _)| pt), path));
+            return p.score;
+        }
+        if !visited.insert((p.location, p.facing)) {
+            continue;
+        }
+        heap.extend(p.options(map));
+    }
+    unreachable!("Shouldn't reach this");
+}
+
+// Need to do cached dfs instead of bfs
+pub fn part_two(maze: &Maze) -> Num {
+    part_two_with(maze, &mut Recorder::off())
+}
+
+/// Ends on every tile of the best paths
+fn part_two_with((map, start, end): &Maze, frames: &mut Recorder) -> Num {
+    let (start, end) = (*start, *end);
+
+    let mut heap = BinaryHeap::<Path>::new();
+    heap.push(Path {
+        steps: vec![(start, Dir::East)],
+        facing: Dir::East,
+        location: start,
+        score: 0,
+    });
+
+    let mut visited = HashMap::<(Point, Dir), (Num, HashSet<(Point, Dir)>)>::new();
+    let mut min_score = None;
+    while let Some(p) = heap.pop() {
+        frames.frame(|| picture(map, visited.keys().map(|(pt, _)| pt), []));
+        if let Some((dist, set)) = visited.get_mut(&(p.location, p.facing)) {
+            if *dist == p.score {
+                set.extend(p.steps);
+            }
+            continue;
+        }
+        if min_score.is_some_and(|s| s < p.score) {
+            break;
+        }
+        visited.insert(
+            (p.location, p.facing),
+            (p.score, p.steps.iter().cloned().collect()),
+        );
+        if p.location == end {
+            min_score = Some(p.score);
+            continue;
+        }
+        heap.extend(p.options(map));
+    }
+    let mut tiles: HashSet<(Point, Dir)> = Dir::ALL
+        .into_iter()
+        .filter_map(|d| visited.get(&(end, d)))
+        .fold(HashSet::new(), |mut acc, (_, next)| {
+            acc.extend(next.iter());
+            acc
+        });
+    let mut prev_len = 0;
+    // Necessary to get paths more than one level of indirection deep
+    while prev_len < tiles.len() {
+        prev_len = tiles.len();
+        tiles = tiles
+            .into_iter()
+            .flat_map(|pt| visited.get(&pt).unwrap().1.iter().cloned())
+            .collect::<HashSet<_>>();
+    }
+    let tiles = tiles.into_iter().map(|(pt, _)| pt).collect::<HashSet<_>>();
+    frames.last(|| picture(map, visited.keys().map(|(pt, _)| pt), &tiles));
+    tiles.len()
+}
+
+/// Tiles the search has reached in blue, with `path` over them in yellow
+fn picture<'a>(
+    map: &Grid<bool>,
+    searched: impl IntoIterator<Item = &'a Point>,
+    path: impl IntoIterator<Item = &'a Point>,
+) -> Frame {
+    let mut colours = Grid::new(map.width(), map.height(), Rgb::BLACK);
+    for (pt, &wall) in map.iter() {
+        if wall {
+            colours[pt] = Rgb::GREY;
+        }
+    }
+    for &pt in searched {
+        colours[pt] = Rgb::BLUE;
+    }
+    for &pt in path {
+        colours[pt] = Rgb::YELLOW;
+    }
+    Frame::new(map.width(), map.height(), |x, y| colours[Point::new(x, y)])
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{differential::Differential, generate::Rng};
+
+    use super::*;
+
+    common::example_tests!(Day16);
+
+    #[test]
+    fn test_parse_error() {
+        assert!(parse("S.E").is_err());
+        assert!(parse("#####\n#S.E.\n#####\n").is_err());
+    }
+
+    /// Lowest score and the tiles on any path with that score, from trying
+    /// every path that doesn't cross itself
+    fn best_paths((map, start, end): &Maze) -> (Num, Num) {
+        fn walk(
+            map: &Grid<bool>,
+            end: Point,
+            (location, facing, score): (Point, Dir, Num),
+            path: &mut Vec<Point>,
+            best: &mut Option<(Num, HashSet<Point>)>,
+        ) {
+            if best.as_ref().is_some_and(|(s, _)| *s < score) {
+                return;
+            }
+            if location == end {
+                match best {
+                    Some((s, tiles)) if *s == score => tiles.extend(path.iter().copied()),
+                    _ => *best = Some((score, path.iter().copied().collect())),
+                }
+                return;
+            }
+            for (dir, cost) in [(facing, 1), (facing.left(), 1001), (facing.right(), 1001)] {
+                let next = dir.offset(location);
+                if !map[next] && !path.contains(&next) {
+                    path.push(next);
+                    walk(map, end, (next, dir, score + cost), path, best);
+                    path.pop();
+                }
+            }
+        }
+
+        let mut best = None;
+        walk(
+            map,
+            *end,
+            (*start, Dir::East, 0),
+            &mut vec![*start],
+            &mut best,
+        );
+        let (score, tiles) = best.expect("The end can be reached");
+        (score, tiles.len())
+    }
+
+    #[test]
+    fn test_differential() {
+        Differential::new().cases(100).check(
+            |rng| {
+                let size = rng.gen_range(4..=7);
+                generate(rng, size)
+            },
+            |input| best_paths(&parse(input).unwrap()),
+            |input| {
+                let maze = parse(input).unwrap();
+                (part_one(&maze), part_two(&maze))
+            },
+        );
+    }
+}
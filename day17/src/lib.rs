@@ -0,0 +1,268 @@
+use common::{
+    generate::{Generated, Rng, SliceRandom, SmallRng},
+    parse::lines,
+    ParseError, Solution,
+};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+type Num = usize;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input<'a> = Debugger;
+    type PartOne = String;
+    type PartTwo = Num;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> String {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Num {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+}
+
+#[derive(Clone)]
+struct Computer<'p> {
+    program: &'p [u8],
+    pointer: usize,
+    a: Num,
+    b: Num,
+    c: Num,
+    output: Vec<u8>,
+}
+
+impl<'p> Computer<'p> {
+    pub fn run(mut self) -> Vec<u8> {
+        // Halts when the opcode or its operand would be past the end
+        while self.pointer + 1 < self.program.len() {
+            self.step();
+        }
+        self.output
+    }
+
+    fn step(&mut self) {
+        let instruction = self.program[self.pointer];
+        let arg = self.program[self.pointer + 1];
+        self.pointer += 2;
+        match instruction {
+            0 => self.a = self.divide(arg),        // adv
+            1 => self.b ^= arg as Num,             // bxl
+            2 => self.b = self.combo(arg) & 0b111, // bst
+            3 => {
+                // jnz
+                if self.a != 0 {
+                    self.pointer = arg as Num
+                }
+            }
+            4 => self.b ^= self.c,
+            5 => self.output.push((self.combo(arg) & 0b0111) as u8),
+            6 => self.b = self.divide(arg),
+            7 => self.c = self.divide(arg),
+            _ => unreachable!(),
+        }
+    }
+
+    /// A divided by 2 to the power of the combo operand, for adv, bdv and cdv
+    fn divide(&self, arg: u8) -> Num {
+        u32::try_from(self.combo(arg))
+            .ok()
+            .and_then(|shift| self.a.checked_shr(shift))
+            .unwrap_or(0)
+    }
+
+    fn combo(&self, arg: u8) -> Num {
+        match arg {
+            a @ 0..=3 => a as Num,
+            4 => self.a,
+            5 => self.b,
+            6 => self.c,
+            7 => panic!("Reserved"),
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Initial register values and the program to run
+#[derive(Debug, Clone)]
+pub struct Debugger {
+    a: Num,
+    b: Num,
+    c: Num,
+    program: Vec<u8>,
+}
+
+pub fn parse(input: &str) -> Result<Debugger, ParseError> {
+    let mut lines = lines(input);
+    let a = lines.expect("register A")?.field("Register A:")?;
+    let b = lines.expect("register B")?.field("Register B:")?;
+    let c = lines.expect("register C")?.field("Register C:")?;
+
+    _ = lines.next();
+    let l = lines.expect("program")?;
+    let (_, program) = l.split_once(l.text, "Program:")?;
+    let program = l.parse_all(program, ",")?;
+    if let Some(&op) = program.iter().find(|&&op| op > 7) {
+        return Err(l.error(format!("{op} is not a 3-bit number")));
+    }
+    if program.len() % 2 != 0 {
+        return Err(l.error("Expected each instruction to have an operand"));
+    }
+    // adv, bst, out, bdv and cdv take combo operands. Instructions start at
+    // even offsets, and at odd ones too if jnz can jump to one.
+    let odd_jump = program.chunks(2).any(|op| op[0] == 3 && op[1] % 2 == 1);
+    let starts = if odd_jump { 0..=1 } else { 0..=0 };
+    for start in starts {
+        if let Some(i) = program[start..]
+            .chunks(2)
+            .position(|op| matches!(op, [0 | 2 | 5 | 6 | 7, 7]))
+        {
+            let offset = start + 2 * i;
+            return Err(l.error(format!(
+                "The instruction at {offset} uses the reserved combo operand 7"
+            )));
+        }
+    }
+    Ok(Debugger { a, b, c, program })
+}
+
+/// A program shaped like the puzzle's, which outputs 3 bits of `A` mixed with
+/// constants on each loop and can be made to output itself. `A` starts with
+/// `size` 3-bit digits, up to 16 of them.
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    let program = loop {
+        let mut middle = [[1, rng.gen_range(0..8)], [4, rng.gen_range(0..8)], [0, 3]];
+        middle.shuffle(rng);
+        let mut program = vec![2, 4, 1, rng.gen_range(0..8), 7, 5];
+        program.extend(middle.concat());
+        program.extend([5, 5, 3, 0]);
+        if solve(0, (0, 0, 0), &program).is_some() {
+            break program;
+        }
+    };
+    let a: Num = rng.gen_range(1..1 << (3 * size.clamp(1, 16)));
+    let program = program.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+    format!(
+        "Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+        program.join(",")
+    )
+}
+
+pub fn part_one(debugger: &Debugger) -> String {
+    let &Debugger {
+        a,
+        b,
+        c,
+        ref program,
+    } = debugger;
+    let computer = Computer {
+        program,
+        pointer: 0,
+        a,
+        b,
+        c,
+        output: Vec::new(),
+    };
+    computer.run().into_iter().fold(String::new(), |str, o| {
+        if str.is_empty() {
+            format!("{o}")
+        } else {
+            format!("{str},{o}")
+        }
+    })
+}
+
+pub fn part_two(debugger: &Debugger) -> Num {
+    // likely need to solve rather than check all numbers Programs all seem
+    // to end in "3,0" and have no other jumps, so we know A = 0 at the end.
+    // However, we don't know the state of B and C at the end of the program, so
+    // we can't just reverse it Also, some instructions (like adv, bdv, and cdv)
+    // are irreversible?
+    let &Debugger {
+        b, c, ref program, ..
+    } = debugger;
+    let a = solve(0, (0, b, c), program).expect("No value of A makes the program output itself");
+    let comp = Computer {
+        program,
+        pointer: 0,
+        a,
+        b,
+        c,
+        output: Vec::new(),
+    }
+    .run();
+    assert_eq!(&comp, program);
+    a
+}
+
+// build incrementally, 3 bits at a time
+// Help from: https://www.reddit.com/r/adventofcode/comments/1hg38ah/comment/m2pyn7q/
+fn solve(i: usize, (a, b, c): (Num, Num, Num), program: &[u8]) -> Option<Num> {
+    let output = Computer {
+        program,
+        pointer: 0,
+        a,
+        b,
+        c,
+        output: Vec::new(),
+    }
+    .run();
+    if output == program {
+        return Some(a);
+    }
+    if program[program.len() - i..] == output || i == 0 {
+        for n in 0..8usize {
+            if let Some(a) = solve(i + 1, ((a << 3) + n, b, c), program) {
+                return Some(a);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day17);
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("Register A: 729\nRegister C: 0\n").unwrap_err();
+        assert_eq!(Some(2), err.line);
+        assert_eq!(
+            "Line 2: Expected `Register B:`\n    Register C: 0",
+            err.to_string()
+        );
+        let err =
+            parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,9\n").unwrap_err();
+        assert_eq!(Some(5), err.line);
+        assert!(parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0\n").is_err());
+        assert!(parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 5,7\n").is_err());
+        // Jumping to 1 runs `5,7`
+        let odd = "Register A: 5\nRegister B: 0\nRegister C: 0\n\nProgram: 3,1,1,5,7,0\n";
+        assert!(parse(odd).is_err());
+        let odd = "Register A: 5\nRegister B: 0\nRegister C: 0\n\nProgram: 3,1,1,5,4,0\n";
+        assert!(parse(odd).is_ok());
+    }
+
+    #[test]
+    fn test_large_shift() {
+        let input = "Register A: 5\nRegister B: 64\nRegister C: 0\n\nProgram: 0,5,5,4,3,0";
+        assert_eq!("0", part_one(&parse(input).unwrap()));
+    }
+}
@@ -0,0 +1,278 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+};
+
+use common::{
+    generate::{Generated, Rng, SliceRandom, SmallRng},
+    parse::each_line,
+    render::{Frame, Recorder, Rgb},
+    Param, Params, ParseError, Solution,
+};
+pub use grid::Point;
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+pub const SIZE: Param = Param {
+    name: "size",
+    default: "70",
+    help: "Largest x and y coordinate in the memory space",
+};
+pub const BYTES: Param = Param {
+    name: "bytes",
+    default: "1024",
+    help: "Bytes that have fallen before finding the shortest path",
+};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    /// Falling bytes, the size and how many have fallen
+    type Input<'a> = (Vec<Point>, usize, usize);
+    type PartOne = usize;
+    type PartTwo = Point;
+
+    const PARAMS: &'static [Param] = &[SIZE, BYTES];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        let (falling, size, bytes) = (parse(input)?, params.get(&SIZE)?, params.get(&BYTES)?);
+        if let Some(pt) = falling.iter().find(|pt| pt.x > size || pt.y > size) {
+            return Err(ParseError::new(format!(
+                "Byte {pt} falls outside a memory space of size {size}"
+            )));
+        }
+        if falling.len() < bytes {
+            return Err(ParseError::new(format!(
+                "Only {} bytes fall, not {bytes}",
+                falling.len()
+            )));
+        }
+        Ok((falling, size, bytes))
+    }
+
+    fn part_one((falling, size, bytes): &Self::Input<'_>) -> usize {
+        part_one(falling, *size, *bytes)
+    }
+
+    fn part_two((falling, size, bytes): &Self::Input<'_>) -> Point {
+        part_two(falling, *size, *bytes)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size)
+    }
+
+    fn animate((falling, size, bytes): &Self::Input<'_>, part: u8, frames: &mut Recorder) {
+        match part {
+            1 => _ = part_one_with(falling, *size, *bytes, frames),
+            _ => _ = part_two_with(falling, *size, *bytes, frames),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Point>, ParseError> {
+    each_line(input, |l| {
+        let (x, y) = l.split_once(l.text, ",")?;
+        Ok(Point::new(l.parse(x)?, l.parse(y)?))
+    })
+}
+
+/// Bytes falling on every point of a memory space `size` wide but the
+/// corners, so the way out is always cut off in the end. Part one looks for a
+/// path before that happens.
+pub fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+    let last = size.max(2) - 1;
+    let (start, end) = (Point::new(0, 0), Point::new(last, last));
+    let mut falling = (0..=last)
+        .flat_map(|x| (0..=last).map(move |y| Point { x, y }))
+        .filter(|&p| p != start && p != end)
+        .collect::<Vec<_>>();
+    falling.shuffle(rng);
+
+    // Find how many bytes can fall with the way out still open
+    let open_after = |bytes| {
+        let mut map = (0..=last)
+            .flat_map(|x| (0..=last).map(move |y| Point { x, y }))
+            .collect::<HashSet<_>>();
+        for pt in &falling[..bytes] {
+            map.remove(pt);
+        }
+        find(start, end, &map).is_some()
+    };
+    let (mut open, mut closed) = (0, falling.len());
+    while closed - open > 1 {
+        let mid = (open + closed) / 2;
+        if open_after(mid) {
+            open = mid;
+        } else {
+            closed = mid;
+        }
+    }
+    let input = falling.iter().map(|pt| format!("{},{}\n", pt.x, pt.y));
+    Generated::new(input.collect())
+        .with(&SIZE, last)
+        .with(&BYTES, rng.gen_range(0..=open))
+}
+
+pub fn part_one(falling: &[Point], size: usize, bytes: usize) -> usize {
+    part_one_with(falling, size, bytes, &mut Recorder::off())
+}
+
+/// Ends on the shortest path
+fn part_one_with(falling: &[Point], size: usize, bytes: usize, frames: &mut Recorder) -> usize {
+    let mut map = (0..=size)
+        .flat_map(|x| (0..=size).map(move |y| Point { x, y }))
+        .collect::<HashSet<_>>();
+
+    for pt in falling.iter().take(bytes) {
+        map.remove(pt);
+        frames.frame(|| picture(size, &map, &HashSet::new(), None));
+    }
+
+    let path = find(Point { x: 0, y: 0 }, Point { x: size, y: size }, &map).unwrap();
+    frames.last(|| picture(size, &map, &path.iter().copied().collect(), None));
+    path.len() - 1
+}
+
+/// Corrupted memory in grey, a path in yellow and the last byte to fall, if
+/// it's the one that cut off the way out, in red
+fn picture(
+    size: usize,
+    map: &HashSet<Point>,
+    path: &HashSet<Point>,
+    cut_off: Option<Point>,
+) -> Frame {
+    Frame::new(size + 1, size + 1, |x, y| match Point::new(x, y) {
+        pt if Some(pt) == cut_off => Rgb::RED,
+        pt if path.contains(&pt) => Rgb::YELLOW,
+        pt if map.contains(&pt) => Rgb::BLACK,
+        _ => Rgb::GREY,
+    })
+}
+
+/// A shortest path from start to end, including both
+fn find(start: Point, end: Point, map: &HashSet<Point>) -> Option<Vec<Point>> {
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((0usize, start, start)));
+    let mut came_from = HashMap::new();
+    loop {
+        let Reverse((dist, pt, prev)) = heap.pop()?;
+        if !map.contains(&pt) || came_from.contains_key(&pt) {
+            continue;
+        }
+        came_from.insert(pt, prev);
+        if pt == end {
+            let mut path = vec![end];
+            while path[path.len() - 1] != start {
+                path.push(came_from[&path[path.len() - 1]]);
+            }
+            return Some(path);
+        }
+        heap.extend(
+            pt.neighbors()
+                .filter(|n| map.contains(n) && !came_from.contains_key(n))
+                .map(|n| Reverse((dist + 1, n, pt))),
+        );
+    }
+}
+
+/// The first byte to cut the start off from the end, once `bytes` have fallen.
+/// Only searches again when a byte lands on the current path.
+pub fn part_two(falling: &[Point], size: usize, bytes: usize) -> Point {
+    part_two_with(falling, size, bytes, &mut Recorder::off())
+}
+
+/// Ends on the byte that cuts off the way out
+fn part_two_with(falling: &[Point], size: usize, bytes: usize, frames: &mut Recorder) -> Point {
+    let mut map = (0..=size)
+        .flat_map(|x| (0..=size).map(move |y| Point { x, y }))
+        .collect::<HashSet<_>>();
+
+    let mut falling_bytes = falling.iter().copied();
+    for _ in 0..bytes {
+        map.remove(&falling_bytes.next().unwrap());
+    }
+    let (start, end) = (Point { x: 0, y: 0 }, Point { x: size, y: size });
+    let mut path = HashSet::new();
+    for byte in falling_bytes {
+        map.remove(&byte);
+        frames.frame(|| picture(size, &map, &path, None));
+        if !path.is_empty() && !path.contains(&byte) {
+            continue;
+        }
+        match find(start, end, &map) {
+            Some(p) => path = p.into_iter().collect(),
+            None => {
+                frames.last(|| picture(size, &map, &path, Some(byte)));
+                return byte;
+            }
+        }
+    }
+    panic!("The way out is never cut off")
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        differential::Differential,
+        generate::{Rng, SmallRng},
+    };
+
+    use super::*;
+
+    const INPUT: &str = include_str!("../examples/example.txt");
+
+    common::example_tests!(Day18);
+
+    #[test]
+    fn test_params() {
+        let params = Params::from_iter([("size", "6"), ("bytes", "12")]);
+        let input = Day18::parse_with(INPUT, &params).unwrap();
+        assert_eq!(22, Day18::part_one(&input));
+        assert!(Day18::parse_with(INPUT, &Params::from_iter([("size", "-1")])).is_err());
+        assert!(Day18::parse_with(INPUT, &Params::from_iter([("size", "5")])).is_err());
+        assert!(Day18::parse_with("5,5\n", &Params::default()).is_err());
+    }
+
+    /// Searches again after every byte
+    fn part_two_by_search(falling: &[Point], size: usize, bytes: usize) -> Point {
+        let mut map = (0..=size)
+            .flat_map(|x| (0..=size).map(move |y| Point { x, y }))
+            .collect::<HashSet<_>>();
+        let mut falling_bytes = falling.iter().copied();
+        for _ in 0..bytes {
+            map.remove(&falling_bytes.next().unwrap());
+        }
+        let mut byte = falling_bytes.next().unwrap();
+        map.remove(&byte);
+        while find(Point { x: 0, y: 0 }, Point { x: size, y: size }, &map).is_some() {
+            byte = falling_bytes.next().unwrap();
+            map.remove(&byte);
+        }
+        byte
+    }
+
+    #[test]
+    fn test_differential() {
+        let solve = |solver: fn(&[Point], usize, usize) -> Point| {
+            move |input: &str| {
+                let falling = parse(input).unwrap();
+                let size = falling.iter().map(|p| p.x).max().unwrap();
+                solver(&falling, size, falling.len() / 3)
+            }
+        };
+        let memory = |rng: &mut SmallRng| {
+            let size = rng.gen_range(2..=9);
+            generate(rng, size).input
+        };
+        Differential::new().check(memory, solve(part_two_by_search), solve(part_two));
+    }
+}
@@ -0,0 +1,142 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use common::{
+    generate::{Generated, Rng, SmallRng},
+    parse::lines,
+    ParseError, Solution,
+};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+type Num = usize;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input<'a> = Onsen<'a>;
+    type PartOne = Num;
+    type PartTwo = Num;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Num {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Num {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+}
+
+/// Available towels, longest first, and the patterns to make
+pub type Onsen<'a> = (Vec<&'a str>, Vec<&'a str>);
+
+pub fn parse(input: &str) -> Result<Onsen<'_>, ParseError> {
+    let mut lines = lines(input);
+    let l = lines.expect("towels")?;
+    let mut towels = l.text.split(',').map(|t| t.trim()).collect::<Vec<_>>();
+    if towels.iter().any(|t| t.is_empty()) {
+        return Err(l.error("Expected comma separated towels"));
+    }
+    towels.sort_by_key(|t| Reverse(t.len()));
+    if let Some(l) = lines.next().filter(|l| !l.text.trim().is_empty()) {
+        return Err(l.error("Expected a blank line after the towels"));
+    }
+    Ok((towels, lines.map(|l| l.text).collect()))
+}
+
+/// A few towels of up to 8 stripes, and `size` designs. Most designs are made
+/// from the towels, though a stripe in some of them has been changed.
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    const COLOURS: &[u8] = b"wubrg";
+    let stripes = |rng: &mut SmallRng, len| {
+        (0..len)
+            .map(|_| COLOURS[rng.gen_range(0..COLOURS.len())] as char)
+            .collect::<String>()
+    };
+    let mut towels = Vec::<String>::new();
+    for _ in 0..(size / 2).clamp(3, 400) {
+        let len = rng.gen_range(1..=8);
+        let towel = stripes(rng, len);
+        if !towels.contains(&towel) {
+            towels.push(towel);
+        }
+    }
+    let mut input = towels.join(", ") + "\n\n";
+    for _ in 0..size.max(1) {
+        let mut design = String::new();
+        while design.len() < 20 {
+            design.push_str(&towels[rng.gen_range(0..towels.len())]);
+        }
+        if rng.gen_bool(0.3) {
+            let i = rng.gen_range(0..design.len());
+            design.replace_range(i..=i, &stripes(rng, 1));
+        }
+        input.push_str(&design);
+        input.push('\n');
+    }
+    input
+}
+
+pub fn part_one((towels, patterns): &Onsen) -> Num {
+    patterns.iter().filter(|p| possible(p, towels)).count()
+}
+
+fn possible(pattern: &str, towels: &[&str]) -> bool {
+    if pattern.is_empty() {
+        return true;
+    }
+    for t in towels {
+        if pattern.starts_with(t) && possible(&pattern[t.len()..], towels) {
+            return true;
+        }
+    }
+    false
+}
+
+pub fn part_two((towels, patterns): &Onsen) -> Num {
+    let mut cache = HashMap::new(); // global since towels are the same
+    patterns
+        .iter()
+        .map(|p| count_possiblities(p, towels, &mut cache))
+        .sum()
+}
+
+fn count_possiblities<'a>(
+    pattern: &'a str,
+    towels: &[&str],
+    cache: &mut HashMap<&'a str, usize>,
+) -> usize {
+    if pattern.is_empty() {
+        return 1; // really caller is 1: t + ""
+    }
+    if let Some(count) = cache.get(pattern) {
+        return *count;
+    }
+    let count = {
+        towels
+            .iter()
+            .filter(|t| pattern.starts_with(**t))
+            .map(|t| count_possiblities(&pattern[t.len()..], towels, cache))
+            .sum()
+    };
+    cache.insert(pattern, count);
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day19);
+}
@@ -0,0 +1,452 @@
+use std::{fmt, ops::RangeInclusive};
+
+use common::{
+    generate::{Generated, Rng, SmallRng},
+    parse::each_line,
+    Param, Params, ParseError, Solution,
+};
+use log::debug;
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+pub const MIN_STEP: Param = Param {
+    name: "min_step",
+    default: "1",
+    help: "Least a safe report's levels can change by between neighbours",
+};
+pub const MAX_STEP: Param = Param {
+    name: "max_step",
+    default: "3",
+    help: "Most a safe report's levels can change by between neighbours",
+};
+pub const REMOVALS: Param = Param {
+    name: "removals",
+    default: "1",
+    help: "Levels the Problem Dampener can remove in part two",
+};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    /// Reports, and the rules for part two. Part one removes no levels.
+    type Input<'a> = (Vec<Vec<i32>>, Rules);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    const PARAMS: &'static [Param] = &[MIN_STEP, MAX_STEP, REMOVALS];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        let (min, max): (i32, i32) = (params.get(&MIN_STEP)?, params.get(&MAX_STEP)?);
+        if min < 0 {
+            return Err(ParseError::new("The least step can't be negative"));
+        }
+        if min > max {
+            return Err(ParseError::new(
+                "The least step can't be more than the most",
+            ));
+        }
+        let rules = Rules {
+            steps: min..=max,
+            removals: params.get(&REMOVALS)?,
+        };
+        Ok((parse(input)?, rules))
+    }
+
+    fn part_one((reports, rules): &Self::Input<'_>) -> usize {
+        part_one(reports, &rules.steps)
+    }
+
+    fn part_two((reports, rules): &Self::Input<'_>) -> usize {
+        part_two(reports, rules)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+}
+
+/// What makes a report safe
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    /// How much levels can change by between neighbours, all rising or all
+    /// falling
+    pub steps: RangeInclusive<i32>,
+    /// Most levels the Problem Dampener can remove
+    pub removals: usize,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            steps: 1..=3,
+            removals: 1,
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    each_line(input, |l| l.parse_all(l.text, " "))
+}
+
+/// `size` reports of 5 to 8 levels. Most change steadily, and some of those
+/// have a bad level or two.
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        let sign = if rng.gen_bool(0.5) { 1 } else { -1 };
+        let mut level: i32 = rng.gen_range(20..=70);
+        let mut report = Vec::new();
+        for _ in 0..rng.gen_range(5..=8) {
+            report.push(level);
+            level += sign * rng.gen_range(1..=3);
+        }
+        for _ in 0..rng.gen_range(0..=2) {
+            let i = rng.gen_range(0..report.len());
+            report[i] += rng.gen_range(-5..=5);
+        }
+        let report = report.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+        input.push_str(&report.join(" "));
+        input.push('\n');
+    }
+    input
+}
+
+pub fn part_one(reports: &[Vec<i32>], steps: &RangeInclusive<i32>) -> usize {
+    let rules = Rules {
+        steps: steps.clone(),
+        removals: 0,
+    };
+    count_safe(reports, &rules)
+}
+
+/// Logs why each report is safe or unsafe at debug level
+pub fn part_two(reports: &[Vec<i32>], rules: &Rules) -> usize {
+    debug!("Reports:\n{}", table(reports, &verdicts(reports, rules)));
+    count_safe(reports, rules)
+}
+
+/// Reports that are safe under `rules`
+pub fn count_safe(reports: &[Vec<i32>], rules: &Rules) -> usize {
+    reports.iter().filter(|r| check(r, rules).is_some()).count()
+}
+
+/// The fewest levels to remove from `report` to make it safe, as indices in
+/// order, or `None` if that takes more than `rules.removals`. Where there's a
+/// choice, earlier levels are kept.
+pub fn check(report: &[i32], rules: &Rules) -> Option<Vec<usize>> {
+    // A step too big for an i32 is outside any range of steps
+    let step = |a: i32, b: i32| b.checked_sub(a).is_some_and(|d| rules.steps.contains(&d));
+    let rising = fewest_removals(report, step);
+    let falling = fewest_removals(report, |a, b| step(b, a));
+    let removed = if falling.len() < rising.len() {
+        falling
+    } else {
+        rising
+    };
+    (removed.len() <= rules.removals).then_some(removed)
+}
+
+/// The fewest levels to remove so that each level left `follows` the one
+/// before it. Finds the best level to keep before each level in turn, which
+/// takes time quadratic in the length of the report rather than trying every
+/// way to remove levels.
+fn fewest_removals(report: &[i32], follows: impl Fn(i32, i32) -> bool) -> Vec<usize> {
+    // Levels removed before `i` if `i` is kept, and the level kept before it
+    let mut best = Vec::<(usize, Option<usize>)>::with_capacity(report.len());
+    for i in 0..report.len() {
+        let keep = (0..i)
+            .filter(|&j| follows(report[j], report[i]))
+            .map(|j| (best[j].0 + i - j - 1, Some(j)))
+            .chain([(i, None)])
+            .min_by_key(|&(removed, _)| removed);
+        best.extend(keep);
+    }
+    let Some(last) = (0..report.len()).min_by_key(|&i| best[i].0 + report.len() - 1 - i) else {
+        return Vec::new();
+    };
+    let mut kept = vec![false; report.len()];
+    let mut i = Some(last);
+    while let Some(k) = i {
+        kept[k] = true;
+        i = best[k].1;
+    }
+    (0..report.len()).filter(|&i| !kept[i]).collect()
+}
+
+/// Why a report is or isn't safe. Indices are into the report, from 0, but
+/// when displayed levels are numbered from 1 as in the puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Safe,
+    /// Safe once the levels at these indices are removed
+    Dampened(Vec<usize>),
+    /// Unsafe even with the Problem Dampener, because of the first problem
+    /// found in the whole report
+    Unsafe(Problem),
+}
+
+/// A level that doesn't follow safely from the one before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Problem {
+    pub reason: Reason,
+    /// Index of the level in the report
+    pub index: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    /// Rises where most of the report falls, or the other way round
+    DirectionChange,
+    StepTooLarge,
+    /// Changes by less than the least step, but not by zero
+    StepTooSmall,
+    StepZero,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Safe => write!(f, "safe"),
+            Verdict::Dampened(removed) => {
+                let removed = removed
+                    .iter()
+                    .map(|i| (i + 1).to_string())
+                    .collect::<Vec<_>>();
+                let s = if removed.len() == 1 { "" } else { "s" };
+                write!(f, "safe without level{s} {}", removed.join(", "))
+            }
+            Verdict::Unsafe(Problem { reason, index }) => {
+                let reason = match reason {
+                    Reason::DirectionChange => "direction changes",
+                    Reason::StepTooLarge => "step too large",
+                    Reason::StepTooSmall => "step too small",
+                    Reason::StepZero => "step of zero",
+                };
+                write!(f, "unsafe: {reason} at level {}", index + 1)
+            }
+        }
+    }
+}
+
+/// Whether `report` is safe under `rules`, and why
+pub fn verdict(report: &[i32], rules: &Rules) -> Verdict {
+    match check(report, rules) {
+        Some(removed) if removed.is_empty() => Verdict::Safe,
+        Some(removed) => Verdict::Dampened(removed),
+        None => Verdict::Unsafe(
+            first_problem(report, &rules.steps).expect("An unsafe report has a problem"),
+        ),
+    }
+}
+
+/// The first level that doesn't follow from the one before in the direction
+/// most of the report goes
+fn first_problem(report: &[i32], steps: &RangeInclusive<i32>) -> Option<Problem> {
+    let diffs = report
+        .windows(2)
+        .map(|w| i64::from(w[1]) - i64::from(w[0]))
+        .collect::<Vec<_>>();
+    let rising = diffs.iter().filter(|d| d.is_positive()).count();
+    let sign = if 2 * rising >= diffs.iter().filter(|&&d| d != 0).count() {
+        1
+    } else {
+        -1
+    };
+    diffs.iter().enumerate().find_map(|(i, &d)| {
+        let reason = match d * sign {
+            step if i32::try_from(step).is_ok_and(|s| steps.contains(&s)) => return None,
+            0 => Reason::StepZero,
+            step if step < 0 => Reason::DirectionChange,
+            step if step > i64::from(*steps.end()) => Reason::StepTooLarge,
+            _ => Reason::StepTooSmall,
+        };
+        Some(Problem {
+            reason,
+            index: i + 1,
+        })
+    })
+}
+
+/// The [`Verdict`] on each report
+pub fn verdicts(reports: &[Vec<i32>], rules: &Rules) -> Vec<Verdict> {
+    reports.iter().map(|r| verdict(r, rules)).collect()
+}
+
+/// A line per report with its levels and its verdict from [`verdicts`].
+/// Reports and levels are both numbered from 1.
+pub fn table(reports: &[Vec<i32>], verdicts: &[Verdict]) -> String {
+    let levels = reports
+        .iter()
+        .map(|r| {
+            r.iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>();
+    let width = levels.iter().map(String::len).max().unwrap_or(0);
+    let mut table = String::new();
+    for (i, (levels, verdict)) in levels.into_iter().zip(verdicts).enumerate() {
+        table += &format!("{:>4}  {levels:<width$}  {verdict}\n", i + 1);
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use common::differential::Differential;
+
+    use super::*;
+
+    common::example_tests!(Day2);
+
+    #[test]
+    fn test_check() {
+        let rules = Rules::default();
+        assert_eq!(Some(vec![]), check(&[7, 6, 4, 2, 1], &rules));
+        assert_eq!(None, check(&[1, 2, 7, 8, 9], &rules));
+        assert_eq!(Some(vec![2]), check(&[1, 3, 2, 4, 5], &rules));
+        assert_eq!(Some(vec![3]), check(&[8, 6, 4, 4, 1], &rules));
+        assert_eq!(Some(vec![0]), check(&[9, 1, 2, 3], &rules));
+
+        let two = Rules {
+            removals: 2,
+            ..rules.clone()
+        };
+        assert_eq!(Some(vec![1, 2]), check(&[1, 9, 9, 2, 3], &two));
+        assert_eq!(None, check(&[1, 9, 9, 2, 3], &rules));
+        let wide = Rules {
+            steps: 1..=5,
+            removals: 0,
+        };
+        assert_eq!(Some(vec![]), check(&[1, 2, 7, 8, 9], &wide));
+
+        let huge = [-2_000_000_000, 2_000_000_000, -2_000_000_000];
+        assert_eq!(Some(vec![1]), check(&huge[..2], &rules));
+        assert_eq!(None, check(&huge, &rules));
+    }
+
+    #[test]
+    fn test_verdict() {
+        let (reports, rules) = Day2::parse(include_str!("../examples/example.txt")).unwrap();
+        let verdicts = verdicts(&reports, &rules);
+        let unsafe_at = |reason, index| Verdict::Unsafe(Problem { reason, index });
+        assert_eq!(
+            vec![
+                Verdict::Safe,
+                unsafe_at(Reason::StepTooLarge, 2),
+                unsafe_at(Reason::StepTooLarge, 3),
+                Verdict::Dampened(vec![2]),
+                Verdict::Dampened(vec![3]),
+                Verdict::Safe,
+            ],
+            verdicts
+        );
+        assert_eq!(
+            unsafe_at(Reason::DirectionChange, 2),
+            verdict(&[1, 2, 1, 0, 4], &rules)
+        );
+        assert_eq!(
+            unsafe_at(Reason::StepZero, 2),
+            verdict(&[1, 2, 2, 2, 4], &rules)
+        );
+        let strict = Rules {
+            steps: 2..=3,
+            removals: 0,
+        };
+        assert_eq!(
+            unsafe_at(Reason::StepTooSmall, 2),
+            verdict(&[1, 3, 4], &strict)
+        );
+        assert_eq!(
+            unsafe_at(Reason::StepTooLarge, 1),
+            verdict(&[-2_000_000_000, 2_000_000_000, -2_000_000_000], &rules)
+        );
+
+        let table = table(&reports[..4], &verdicts[..4]);
+        assert_eq!(
+            "   1  7 6 4 2 1  safe\n   2  1 2 7 8 9  unsafe: step too large at level 3\n   \
+             3  9 7 6 2 1  unsafe: step too large at level 4\n   4  1 3 2 4 5  safe without \
+             level 3\n",
+            table
+        );
+        assert_eq!(
+            "safe without levels 2, 3",
+            Verdict::Dampened(vec![1, 2]).to_string()
+        );
+    }
+
+    #[test]
+    fn test_params() {
+        let input = include_str!("../examples/example.txt");
+        let params = Params::from_iter([("removals", "0")]);
+        let day = Day2::parse_with(input, &params).unwrap();
+        assert_eq!(2, Day2::part_two(&day));
+        let params = Params::from_iter([("min_step", "4"), ("max_step", "3")]);
+        assert!(Day2::parse_with(input, &params).is_err());
+        let params = Params::from_iter([("min_step", "-1")]);
+        assert!(Day2::parse_with(input, &params).is_err());
+        let params = Params::from_iter([("max_step", "4294967295")]);
+        assert!(Day2::parse_with(input, &params).is_err());
+    }
+
+    /// Tries removing every set of up to `rules.removals` levels
+    fn safe_by_search(report: &[i32], rules: &Rules) -> bool {
+        let safe = |levels: &[i32]| {
+            let diffs = levels.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>();
+            diffs.iter().all(|d| rules.steps.contains(d))
+                || diffs.iter().all(|d| rules.steps.contains(&-d))
+        };
+        (0u32..1 << report.len())
+            .filter(|mask| mask.count_ones() as usize <= rules.removals)
+            .any(|mask| {
+                let kept = (0..report.len())
+                    .filter(|i| mask & 1 << i == 0)
+                    .map(|i| report[i])
+                    .collect::<Vec<_>>();
+                safe(&kept)
+            })
+    }
+
+    #[test]
+    fn test_differential() {
+        Differential::new().check(
+            |rng| {
+                let size = rng.gen_range(1..20);
+                generate(rng, size)
+            },
+            |input| {
+                let reports = parse(input).unwrap();
+                (0..=3)
+                    .map(|removals| {
+                        let rules = Rules {
+                            removals,
+                            ..Rules::default()
+                        };
+                        reports.iter().filter(|r| safe_by_search(r, &rules)).count()
+                    })
+                    .collect::<Vec<_>>()
+            },
+            |input| {
+                let reports = parse(input).unwrap();
+                (0..=3)
+                    .map(|removals| {
+                        let rules = Rules {
+                            removals,
+                            ..Rules::default()
+                        };
+                        part_two(&reports, &rules)
+                    })
+                    .collect::<Vec<_>>()
+            },
+        );
+    }
+}
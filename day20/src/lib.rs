@@ -0,0 +1,273 @@
+use std::collections::{HashMap, HashSet};
+
+use common::{
+    generate::{self, Generated, SliceRandom, SmallRng},
+    parse,
+    render::{Frame, Recorder, Rgb},
+    Param, Params, ParseError, Solution,
+};
+use grid::{Dir, Point};
+use log::trace;
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+type Num = usize;
+
+pub const SAVING: Param = Param {
+    name: "saving",
+    default: "100",
+    help: "Picoseconds a cheat must save to be counted",
+};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    /// The track and the least time a cheat must save
+    type Input<'a> = (RaceTrack, Num);
+    type PartOne = Num;
+    type PartTwo = Num;
+
+    const PARAMS: &'static [Param] = &[SAVING];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        Ok((parse(input)?, params.get(&SAVING)?))
+    }
+
+    fn part_one((track, saving): &Self::Input<'_>) -> Num {
+        part_one(track, *saving)
+    }
+
+    fn part_two((track, saving): &Self::Input<'_>) -> Num {
+        part_two(track, *saving)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size)
+    }
+
+    fn animate((track, saving): &Self::Input<'_>, part: u8, frames: &mut Recorder) {
+        match part {
+            1 => _ = part_one_with(track, *saving, frames),
+            _ => _ = part_two_with(track, *saving, frames),
+        }
+    }
+}
+
+/// Open spaces, start and end of the race track
+pub type RaceTrack = (HashSet<Point>, Point, Point);
+
+/// A track about `size` tiles wide, winding through a random maze from the
+/// top left, with no branches. Cheats must save a tenth of the race.
+pub fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+    let size = size.max(5) | 1;
+    // Carve a maze between the odd squares, then keep one route through it
+    let start = Point::new(1, 1);
+    let mut came_from = HashMap::from([(start, start)]);
+    let mut stack = vec![start];
+    while let Some(&pt) = stack.last() {
+        let mut next = Dir::ALL
+            .into_iter()
+            .filter_map(|d| pt.checked_move(d)?.checked_move(d))
+            .filter(|n| n.x < size - 1 && n.y < size - 1 && !came_from.contains_key(n))
+            .collect::<Vec<_>>();
+        next.shuffle(rng);
+        match next.first() {
+            Some(&n) => {
+                came_from.insert(n, pt);
+                stack.push(n);
+            }
+            None => _ = stack.pop(),
+        }
+    }
+    let end = *came_from
+        .keys()
+        .filter(|&&pt| pt != start)
+        .collect::<Vec<_>>()
+        .choose(rng)
+        .unwrap();
+    let mut track = HashSet::from([*end]);
+    let mut pt = *end;
+    while pt != start {
+        let prev = came_from[&pt];
+        track.insert(Point::new((pt.x + prev.x) / 2, (pt.y + prev.y) / 2));
+        track.insert(prev);
+        pt = prev;
+    }
+    let saving = track.len() / 10;
+    let input = generate::grid(size, size, |x, y| match Point::new(x, y) {
+        pt if pt == start => 'S',
+        pt if pt == *end => 'E',
+        pt if track.contains(&pt) => '.',
+        _ => '#',
+    });
+    Generated::new(input).with(&SAVING, saving.max(1))
+}
+
+pub fn parse(input: &str) -> Result<RaceTrack, ParseError> {
+    parse::grid(input, "#.SE")?;
+    let (mut start, mut end) = (None, None);
+    let track = input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, l)| l.chars().enumerate().map(move |(x, c)| (x, y, c)))
+        .filter_map(|(x, y, c)| match c {
+            '.' => Some(Point::new(x, y)),
+            'S' => {
+                start = Some(Point::new(x, y));
+                start
+            }
+            'E' => {
+                end = Some(Point::new(x, y));
+                end
+            }
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+    Ok((
+        track,
+        start.ok_or_else(|| ParseError::new("No start on the track"))?,
+        end.ok_or_else(|| ParseError::new("No end on the track"))?,
+    ))
+}
+
+pub fn part_one(track: &RaceTrack, saving: Num) -> Num {
+    part_one_with(track, saving, &mut Recorder::off())
+}
+
+/// A frame for each tile of the race, checked for cheats in turn
+fn part_one_with((track, start, end): &RaceTrack, saving: Num, frames: &mut Recorder) -> Num {
+    let path = path(*start, *end, track).unwrap();
+    let mut cheats = HashMap::new();
+    for (i, &s) in path.iter().enumerate() {
+        frames.frame(|| picture(track, &path[..=i]));
+        // Should filter that e is after s
+        s.within(2)
+            .filter(|e| e.dist(&s) == 2)
+            .filter_map(|e| path[i..].iter().position(|&p| p == e).map(|d| (e, d - 2)))
+            .filter(|&(_, d)| d != 0)
+            .for_each(|(e, d)| {
+                trace!("{s}-{e}: {d}");
+                cheats.insert((s, e), d);
+            })
+    }
+    frames.last(|| picture(track, &path));
+    cheats.into_iter().filter(|(_, s)| *s >= saving).count()
+}
+
+/// Walls in grey, the race so far in blue and where it has got to in red
+fn picture(track: &HashSet<Point>, raced: &[Point]) -> Frame {
+    // The track is walled in, so the walls reach one past it
+    let width = track.iter().map(|pt| pt.x).max().unwrap_or(0) + 2;
+    let height = track.iter().map(|pt| pt.y).max().unwrap_or(0) + 2;
+    let raced_set = raced.iter().collect::<HashSet<_>>();
+    Frame::new(width, height, |x, y| match Point::new(x, y) {
+        pt if raced.last() == Some(&pt) => Rgb::RED,
+        pt if raced_set.contains(&pt) => Rgb::BLUE,
+        pt if track.contains(&pt) => Rgb::BLACK,
+        _ => Rgb::GREY,
+    })
+}
+
+/// Steps from a to b, including both a and b
+fn path(a: Point, b: Point, track: &HashSet<Point>) -> Option<Vec<Point>> {
+    if !track.contains(&a) || !track.contains(&b) {
+        return None;
+    }
+    let dirs = Dir::ALL
+        .into_iter()
+        .filter(|d| a.checked_move(*d).is_some_and(|pt| track.contains(&pt)));
+    for d in dirs {
+        let mut path = vec![a];
+        let mut point = a;
+        let mut dir = d;
+        while let Some((p, d)) = next_point(point, dir, track) {
+            point = p;
+            dir = d;
+            path.push(point);
+            if point == b {
+                return Some(path);
+            }
+        }
+    }
+    None
+}
+
+fn next_point(init: Point, dir: Dir, track: &HashSet<Point>) -> Option<(Point, Dir)> {
+    if let Some(pt) = init.checked_move(dir).filter(|pt| track.contains(pt)) {
+        return Some((pt, dir));
+    }
+    if let Some(pt) = init
+        .checked_move(dir.left())
+        .filter(|pt| track.contains(pt))
+    {
+        return Some((pt, dir.left()));
+    }
+    if let Some(pt) = init
+        .checked_move(dir.right())
+        .filter(|pt| track.contains(pt))
+    {
+        return Some((pt, dir.right()));
+    }
+    None
+}
+
+pub fn part_two(track: &RaceTrack, saving: Num) -> Num {
+    part_two_with(track, saving, &mut Recorder::off())
+}
+
+/// A frame for each tile of the race, checked for cheats in turn
+fn part_two_with((track, start, end): &RaceTrack, saving: Num, frames: &mut Recorder) -> Num {
+    let path = path(*start, *end, track).unwrap();
+
+    let mut cheats = 0;
+    for (i, &s) in path.iter().enumerate() {
+        frames.frame(|| picture(track, &path[..=i]));
+        cheats += s
+            .within(20)
+            .filter(|e| track.contains(e))
+            .filter_map(|e| {
+                path[i..]
+                    .iter()
+                    .position(|&p| p == e)
+                    .map(|d| d - e.dist(&s))
+            })
+            .filter(|&d| d >= saving)
+            .count();
+    }
+    frames.last(|| picture(track, &path));
+    cheats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../examples/example.txt");
+
+    common::example_tests!(Day20);
+
+    #[test]
+    fn test_part_one() {
+        let track = parse(INPUT).unwrap();
+        assert_eq!(0, part_one(&track, 100));
+        assert_eq!(1, part_one(&track, 64));
+        assert_eq!(5, part_one(&track, 20));
+        assert_eq!(44, part_one(&track, 1));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let track = parse(INPUT).unwrap();
+        assert_eq!(0, part_two(&track, 100));
+        assert_eq!(3, part_two(&track, 76));
+        assert_eq!(285, part_two(&track, 50));
+    }
+}
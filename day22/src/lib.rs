@@ -0,0 +1,128 @@
+use std::collections::{HashMap, HashSet};
+
+use common::{
+    generate::{Generated, Rng, SmallRng},
+    parse::each_line,
+    Param, Params, ParseError, Solution,
+};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+type Num = usize;
+
+pub const SECRETS: Param = Param {
+    name: "secrets",
+    default: "2000",
+    help: "New secret numbers each buyer generates",
+};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    /// Initial secrets, and how many each buyer generates
+    type Input<'a> = (Vec<Num>, usize);
+    type PartOne = Num;
+    type PartTwo = Num;
+
+    const PARAMS: &'static [Param] = &[SECRETS];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        Ok((parse(input)?, params.get(&SECRETS)?))
+    }
+
+    fn part_one((secrets, count): &Self::Input<'_>) -> Num {
+        part_one(secrets, *count)
+    }
+
+    fn part_two((secrets, count): &Self::Input<'_>) -> Num {
+        part_two(secrets, *count)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+}
+
+fn next_secret(mut secret: usize) -> usize {
+    const PRUNE: usize = 16777216;
+    secret ^= secret * 64;
+    secret %= PRUNE;
+    secret ^= secret / 32;
+    secret %= PRUNE;
+    secret ^= secret * 2048;
+    secret %= PRUNE;
+    secret
+}
+
+fn price(secret: usize) -> i8 {
+    (secret % 10) as i8
+}
+
+/// `size` initial secret numbers
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| format!("{}\n", rng.gen_range(1..1 << 24)))
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Num>, ParseError> {
+    each_line(input, |l| l.parse(l.text))
+}
+
+pub fn part_one(secrets: &[Num], count: usize) -> Num {
+    secrets
+        .iter()
+        .copied()
+        .map(|mut s| {
+            (0..count).for_each(|_| s = next_secret(s));
+            s
+        })
+        .sum()
+}
+
+pub fn part_two(secrets: &[Num], count: usize) -> Num {
+    let changes = secrets
+        .iter()
+        .copied()
+        .map(|mut s| {
+            let mut prices = Vec::new();
+            let mut diffs = Vec::new();
+            let mut prev = price(s);
+            for _ in 0..count {
+                s = next_secret(s);
+                let price = price(s);
+                prices.push(price);
+                diffs.push(price - prev);
+                prev = price;
+            }
+            (prices, diffs)
+        })
+        .flat_map(|(pv, dv)| {
+            let mut filter = HashSet::new();
+            dv.windows(4)
+                .zip(pv.into_iter().skip(3))
+                .map(|(seq, price)| (seq.try_into().unwrap(), price as usize))
+                .filter(|(seq, _)| filter.insert(*seq))
+                .collect::<Vec<_>>()
+        })
+        .fold(HashMap::<[i8; 4], usize>::new(), |mut m, (k, v)| {
+            *m.entry(k).or_insert(0usize) += v;
+            m
+        });
+    changes.into_iter().max_by_key(|(_, v)| *v).unwrap().1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day22);
+}
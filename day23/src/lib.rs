@@ -0,0 +1,158 @@
+use std::collections::{BTreeSet, HashMap};
+
+use common::{
+    generate::{Generated, Rng, SliceRandom, SmallRng},
+    parse::each_line,
+    ParseError, Solution,
+};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+type Num = usize;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Input<'a> = Network<'a>;
+    type PartOne = Num;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Num {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> String {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+}
+
+/// Computers connected to each computer
+pub type Network<'a> = HashMap<&'a str, BTreeSet<&'a str>>;
+
+/// A network of up to `size` computers with a few links each, and one larger
+/// group of computers all linked to each other
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    let mut computers = (b'a'..=b'z')
+        .flat_map(|a| (b'a'..=b'z').map(move |b| format!("{}{}", a as char, b as char)))
+        .collect::<Vec<_>>();
+    computers.shuffle(rng);
+    computers.truncate(size.clamp(3, computers.len()));
+    let mut links = Vec::new();
+    for (i, a) in computers.iter().enumerate() {
+        for _ in 0..rng.gen_range(1..=4) {
+            let b = &computers[rng.gen_range(0..computers.len())];
+            if a != b {
+                links.push(format!("{a}-{b}"));
+            }
+        }
+        if i < (5 + size / 50).min(computers.len()) {
+            links.extend(computers[..i].iter().map(|b| format!("{a}-{b}")));
+        }
+    }
+    links.shuffle(rng);
+    links.iter().map(|l| format!("{l}\n")).collect()
+}
+
+pub fn parse(input: &str) -> Result<Network<'_>, ParseError> {
+    let links = each_line(input, |l| {
+        let (a, b) = l.split_once(l.text.trim(), "-")?;
+        if a.is_empty() || b.is_empty() || a == b {
+            return Err(l.error("Expected a link between two computers"));
+        }
+        Ok((a, b))
+    })?;
+    let mut network = Network::new();
+    for (a, b) in links {
+        network.entry(a).or_default().insert(b);
+        network.entry(b).or_default().insert(a);
+    }
+    Ok(network)
+}
+
+/// Sets of three connected computers where at least one starts with `t`
+pub fn part_one(network: &Network) -> Num {
+    let mut count = 0;
+    for (a, links) in network {
+        // Only count each triangle from its smallest computer
+        for b in links.range::<&str, _>(a..) {
+            for c in network[b].range::<&str, _>(b..) {
+                if links.contains(c) && [a, b, c].iter().any(|n| n.starts_with('t')) {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
+
+/// The largest set of computers all connected to each other, sorted and
+/// joined with commas
+pub fn part_two(network: &Network) -> String {
+    let mut largest = BTreeSet::new();
+    bron_kerbosch(
+        network,
+        BTreeSet::new(),
+        network.keys().copied().collect(),
+        BTreeSet::new(),
+        &mut largest,
+    );
+    largest.into_iter().collect::<Vec<_>>().join(",")
+}
+
+/// Visits every maximal clique that extends `clique` with computers from
+/// `candidates`, keeping the largest. `excluded` holds computers whose
+/// cliques have already been visited.
+fn bron_kerbosch<'a>(
+    network: &Network<'a>,
+    clique: BTreeSet<&'a str>,
+    mut candidates: BTreeSet<&'a str>,
+    mut excluded: BTreeSet<&'a str>,
+    largest: &mut BTreeSet<&'a str>,
+) {
+    if candidates.is_empty() && excluded.is_empty() {
+        if clique.len() > largest.len() {
+            *largest = clique;
+        }
+        return;
+    }
+    // Any maximal clique contains the pivot or one of its non-neighbours
+    let pivot = candidates
+        .union(&excluded)
+        .max_by_key(|n| network[*n].intersection(&candidates).count())
+        .copied()
+        .unwrap();
+    let skip = &network[pivot];
+    for n in candidates.clone().difference(skip) {
+        let links = &network[n];
+        let mut clique = clique.clone();
+        clique.insert(n);
+        bron_kerbosch(
+            network,
+            clique,
+            candidates.intersection(links).copied().collect(),
+            excluded.intersection(links).copied().collect(),
+            largest,
+        );
+        candidates.remove(n);
+        excluded.insert(n);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day23);
+}
@@ -0,0 +1,3 @@
+fn main() {
+    common::solution::main::<day23::Day23>(day23::EMBEDDED_INPUT);
+}
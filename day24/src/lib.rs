@@ -0,0 +1,420 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use common::{
+    generate::{Generated, Rng, SliceRandom, SmallRng},
+    parse::{lines, Line},
+    ParseError, Solution,
+};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+type Num = u64;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input<'a> = Device<'a>;
+    type PartOne = Num;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Num {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> String {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+impl Op {
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            Op::And => a && b,
+            Op::Or => a || b,
+            Op::Xor => a ^ b,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gate<'a> {
+    inputs: [&'a str; 2],
+    op: Op,
+    output: &'a str,
+}
+
+/// Initial wire values and the gates connecting them
+#[derive(Debug, Clone)]
+pub struct Device<'a> {
+    wires: HashMap<&'a str, bool>,
+    gates: Vec<Gate<'a>>,
+}
+
+/// An adder of two `size` bit numbers, up to 44 bits, wired like the puzzle's
+/// with four pairs of gate outputs swapped
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    let bits = size.clamp(2, 44);
+    let mut names = HashSet::new();
+    let mut name = |rng: &mut SmallRng| loop {
+        let name = (0..3)
+            .map(|_| char::from(rng.gen_range(b'a'..b'x')))
+            .collect::<String>();
+        if names.insert(name.clone()) {
+            break name;
+        }
+    };
+    // Inputs and output of each gate
+    let mut gates = vec![
+        (
+            ["x00".to_owned(), "y00".to_owned()],
+            "XOR",
+            "z00".to_owned(),
+        ),
+        (["x00".to_owned(), "y00".to_owned()], "AND", name(rng)),
+    ];
+    for i in 1..bits {
+        let (x, y, z) = (format!("x{i:02}"), format!("y{i:02}"), format!("z{i:02}"));
+        let carry = gates.last().unwrap().2.clone();
+        let (sum, a, b) = (name(rng), name(rng), name(rng));
+        let carry_out = if i == bits - 1 {
+            format!("z{bits:02}")
+        } else {
+            name(rng)
+        };
+        gates.extend([
+            ([x.clone(), y.clone()], "XOR", sum.clone()),
+            ([x, y], "AND", a.clone()),
+            ([sum.clone(), carry.clone()], "XOR", z),
+            ([sum, carry], "AND", b.clone()),
+            ([a, b], "OR", carry_out),
+        ]);
+    }
+    // Swap outputs, as long as no gate ends up depending on itself
+    let mut swapped = 0;
+    while swapped < 4 {
+        let (i, j) = (rng.gen_range(0..gates.len()), rng.gen_range(0..gates.len()));
+        if i == j {
+            continue;
+        }
+        let mut rewired = gates.clone();
+        let out = rewired[i].2.clone();
+        rewired[i].2 = std::mem::replace(&mut rewired[j].2, out);
+        let sources = rewired
+            .iter()
+            .map(|(inputs, _, out)| (out.as_str(), inputs))
+            .collect::<HashMap<_, _>>();
+        let mut depth = HashMap::new();
+        let acyclic = rewired
+            .iter()
+            .all(|(_, _, out)| gate_depth(out, &sources, &mut depth).is_some());
+        if acyclic {
+            gates = rewired;
+            swapped += 1;
+        }
+    }
+    gates.shuffle(rng);
+
+    let mut input = String::new();
+    for wire in ['x', 'y'] {
+        for i in 0..bits {
+            input.push_str(&format!("{wire}{i:02}: {}\n", rng.gen_range(0..=1)));
+        }
+    }
+    input.push('\n');
+    for ([a, b], op, out) in gates {
+        input.push_str(&format!("{a} {op} {b} -> {out}\n"));
+    }
+    input
+}
+
+/// How many gates deep `wire` is, or `None` if it depends on itself
+fn gate_depth<'a>(
+    wire: &'a str,
+    sources: &HashMap<&'a str, &'a [String; 2]>,
+    depth: &mut HashMap<&'a str, Option<usize>>,
+) -> Option<usize> {
+    match depth.get(wire) {
+        Some(&d) => return d,
+        None => _ = depth.insert(wire, None),
+    }
+    let d = match sources.get(wire) {
+        Some(inputs) => inputs
+            .iter()
+            .map(|w| gate_depth(w, sources, depth))
+            .try_fold(0, |d, w| Some(d.max(w? + 1)))?,
+        None => 0,
+    };
+    depth.insert(wire, Some(d));
+    Some(d)
+}
+
+pub fn parse(input: &str) -> Result<Device<'_>, ParseError> {
+    let mut lines = lines(input);
+    let mut wires = HashMap::new();
+    for l in lines.section() {
+        let (wire, value) = l.split_once(l.text, ":")?;
+        let value = match value.trim() {
+            "0" => false,
+            "1" => true,
+            _ => return Err(l.error("Expected a wire value of 0 or 1")),
+        };
+        wires.insert(wire.trim(), value);
+    }
+
+    let mut gates = Vec::new();
+    let mut outputs = HashSet::new();
+    for l in lines.filter(|l| !l.text.trim().is_empty()) {
+        let (gate, output) = l.split_once(l.text, "->")?;
+        let output = output.trim();
+        let [a, op, b] = gate.split_whitespace().collect::<Vec<_>>()[..] else {
+            return Err(l.error("Expected a gate like `x00 AND y00 -> z00`"));
+        };
+        let op = match op {
+            "AND" => Op::And,
+            "OR" => Op::Or,
+            "XOR" => Op::Xor,
+            _ => return Err(l.error(format!("Unknown gate `{op}`"))),
+        };
+        if wires.contains_key(output) || !outputs.insert(output) {
+            return Err(l.error(format!("`{output}` already has a value")));
+        }
+        gates.push((
+            l,
+            Gate {
+                inputs: [a, b],
+                op,
+                output,
+            },
+        ));
+    }
+    for (l, g) in &gates {
+        if let Some(w) = g
+            .inputs
+            .iter()
+            .find(|w| !wires.contains_key(*w) && !outputs.contains(*w))
+        {
+            return Err(l.error(format!("Nothing sets `{w}`")));
+        }
+    }
+    if let Some((l, wire)) = cycle(&gates) {
+        return Err(l.error(format!("`{wire}` depends on itself")));
+    }
+    let gates = gates.into_iter().map(|(_, g)| g).collect();
+    Ok(Device { wires, gates })
+}
+
+/// A gate whose output feeds back into its own inputs, and a wire on that
+/// loop, found by sorting the gates so each comes after the ones it reads
+fn cycle<'a, 'l>(gates: &[(Line<'l>, Gate<'a>)]) -> Option<(Line<'l>, &'a str)> {
+    let index = gates
+        .iter()
+        .enumerate()
+        .map(|(i, (_, g))| (g.output, i))
+        .collect::<HashMap<_, _>>();
+    let mut readers = vec![Vec::new(); gates.len()];
+    let mut waiting = vec![0; gates.len()];
+    for (i, (_, g)) in gates.iter().enumerate() {
+        for w in &g.inputs {
+            if let Some(&j) = index.get(w) {
+                readers[j].push(i);
+                waiting[i] += 1;
+            }
+        }
+    }
+    let mut ready = (0..gates.len())
+        .filter(|&i| waiting[i] == 0)
+        .collect::<Vec<_>>();
+    let mut sorted = 0;
+    while let Some(i) = ready.pop() {
+        sorted += 1;
+        for &r in &readers[i] {
+            waiting[r] -= 1;
+            if waiting[r] == 0 {
+                ready.push(r);
+            }
+        }
+    }
+    if sorted == gates.len() {
+        return None;
+    }
+    // Any unsorted gate is on a loop or after one. Going back through
+    // unsorted inputs for as many steps as there are gates must reach a loop.
+    let mut i = (0..gates.len()).find(|&i| waiting[i] > 0)?;
+    for _ in 0..gates.len() {
+        i = gates[i]
+            .1
+            .inputs
+            .iter()
+            .filter_map(|w| index.get(w).copied())
+            .find(|&j| waiting[j] > 0)?;
+    }
+    Some((gates[i].0, gates[i].1.output))
+}
+
+fn value<'a>(
+    wire: &'a str,
+    gates: &HashMap<&'a str, Gate<'a>>,
+    wires: &mut HashMap<&'a str, bool>,
+) -> bool {
+    if let Some(&v) = wires.get(wire) {
+        return v;
+    }
+    let gate = gates[wire];
+    let [a, b] = gate.inputs.map(|w| value(w, gates, wires));
+    let v = gate.op.apply(a, b);
+    wires.insert(wire, v);
+    v
+}
+
+/// The number made by the bits on the `z` wires, `z00` being least significant
+pub fn part_one(device: &Device) -> Num {
+    let gates = device
+        .gates
+        .iter()
+        .map(|g| (g.output, *g))
+        .collect::<HashMap<_, _>>();
+    let mut wires = device.wires.clone();
+    let mut z = gates
+        .keys()
+        .copied()
+        .filter(|w| w.starts_with('z'))
+        .collect::<Vec<_>>();
+    z.sort_unstable();
+    z.iter()
+        .rev()
+        .fold(0, |n, w| n << 1 | value(w, &gates, &mut wires) as Num)
+}
+
+/// The device is meant to be a ripple carry adder of `x` and `y` into `z`,
+/// but four pairs of gates have had their outputs swapped. Every bit after the
+/// first should be wired as:
+///
+/// ```text
+/// x XOR y -> s        s XOR carry_in -> z
+/// x AND y -> a        s AND carry_in -> b        a OR b -> carry_out
+/// ```
+///
+/// so any gate whose output doesn't fit in that shape is one of the swapped
+/// wires. Returns them sorted and joined with commas.
+pub fn part_two(device: &Device) -> String {
+    let last_z = device
+        .gates
+        .iter()
+        .map(|g| g.output)
+        .filter(|w| w.starts_with('z'))
+        .max()
+        .unwrap_or_default();
+    let feeds = |wire: &str, op: Op| {
+        device
+            .gates
+            .iter()
+            .any(|g| g.op == op && g.inputs.contains(&wire))
+    };
+    let first_bit = |g: &Gate| g.inputs.contains(&"x00");
+    let from_xy = |g: &Gate| g.inputs.iter().all(|w| w.starts_with(['x', 'y']));
+
+    let mut swapped = BTreeSet::new();
+    for g in &device.gates {
+        let wrong = if g.output == last_z {
+            // The final carry goes straight to the top bit
+            g.op != Op::Or
+        } else if g.output.starts_with('z') {
+            g.op != Op::Xor
+        } else {
+            match g.op {
+                Op::Xor if !from_xy(g) => true,
+                Op::Xor => !first_bit(g) && !feeds(g.output, Op::Xor),
+                Op::And => !first_bit(g) && !feeds(g.output, Op::Or),
+                Op::Or => false,
+            }
+        };
+        if wrong {
+            swapped.insert(g.output);
+        }
+    }
+    swapped.into_iter().collect::<Vec<_>>().join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day24);
+
+    /// A working `bits` wide adder of `x` and `y`
+    fn adder(bits: usize, x: u64, y: u64) -> String {
+        let mut input = String::new();
+        for (name, n) in [('x', x), ('y', y)] {
+            for i in 0..bits {
+                input += &format!("{name}{i:02}: {}\n", n >> i & 1);
+            }
+        }
+        input += "\nx00 XOR y00 -> z00\nx00 AND y00 -> c00\n";
+        for i in 1..bits {
+            let p = i - 1;
+            let carry = if i == bits - 1 {
+                format!("z{bits:02}")
+            } else {
+                format!("c{i:02}")
+            };
+            input += &format!(
+                "x{i:02} XOR y{i:02} -> s{i:02}\n\
+                 s{i:02} XOR c{p:02} -> z{i:02}\n\
+                 x{i:02} AND y{i:02} -> a{i:02}\n\
+                 s{i:02} AND c{p:02} -> b{i:02}\n\
+                 a{i:02} OR b{i:02} -> {carry}\n"
+            );
+        }
+        input
+    }
+
+    #[test]
+    fn test_part_one() {
+        let expected: Num = 27 + 45;
+        assert_eq!(expected, part_one(&parse(&adder(6, 27, 45)).unwrap()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = adder(6, 27, 45);
+        assert_eq!("", part_two(&parse(&input).unwrap()));
+
+        let swapped = input
+            .replace("-> z02", "-> tmp")
+            .replace("-> a02", "-> z02")
+            .replace("-> tmp", "-> a02")
+            .replace("-> s04", "-> tmp")
+            .replace("-> a04", "-> s04")
+            .replace("-> tmp", "-> a04");
+        let expected = "a02,a04,s04,z02";
+        assert_eq!(expected, part_two(&parse(&swapped).unwrap()))
+    }
+
+    #[test]
+    fn test_cycle() {
+        let err = parse("x00: 1\n\nx00 AND b -> a\nx00 AND a -> b\n").unwrap_err();
+        assert!(err.to_string().contains("depends on itself"));
+        // A gate reading from a loop isn't reported, only the loop
+        let err = parse("x00: 1\n\nx00 AND a -> c\nx00 AND b -> a\nx00 AND a -> b\n").unwrap_err();
+        assert!(!err.to_string().contains("`c`"));
+    }
+}
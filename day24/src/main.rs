@@ -0,0 +1,3 @@
+fn main() {
+    common::solution::main::<day24::Day24>(day24::EMBEDDED_INPUT);
+}
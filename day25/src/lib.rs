@@ -0,0 +1,125 @@
+use common::{
+    generate::{Generated, Rng, SmallRng},
+    parse::lines,
+    ParseError, Solution,
+};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+type Num = usize;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    type Input<'a> = Schematics;
+    type PartOne = Num;
+    type PartTwo = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Num {
+        part_one(input)
+    }
+
+    fn part_two(_: &Self::Input<'_>) -> &'static str {
+        // There's no puzzle for the last star
+        "Merry Christmas!"
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+}
+
+const WIDTH: usize = 5;
+const HEIGHT: usize = 7;
+
+/// Pin heights of each lock and key
+#[derive(Debug, Clone, Default)]
+pub struct Schematics {
+    locks: Vec<[u8; WIDTH]>,
+    keys: Vec<[u8; WIDTH]>,
+}
+
+/// `size` locks and keys with random pin heights
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    let mut schematics = Vec::new();
+    for _ in 0..size.max(1) {
+        let heights: [usize; WIDTH] = std::array::from_fn(|_| rng.gen_range(0..HEIGHT - 1));
+        let lock = rng.gen_bool(0.5);
+        let rows = (0..HEIGHT).map(|y| {
+            // Count rows from the solid end, which is the top of a lock
+            let row = if lock { y } else { HEIGHT - 1 - y };
+            heights
+                .iter()
+                .map(|&h| if row <= h { '#' } else { '.' })
+                .collect::<String>()
+        });
+        schematics.push(rows.collect::<Vec<_>>().join("\n"));
+    }
+    schematics.join("\n\n") + "\n"
+}
+
+pub fn parse(input: &str) -> Result<Schematics, ParseError> {
+    let mut lines = lines(input);
+    let mut schematics = Schematics::default();
+    loop {
+        let rows = lines.section();
+        let Some(first) = rows.first() else {
+            break;
+        };
+        if rows.len() != HEIGHT {
+            return Err(first.error(format!("Expected a schematic {HEIGHT} rows tall")));
+        }
+        let mut heights = [0; WIDTH];
+        for l in &rows {
+            if l.text.len() != WIDTH || l.text.contains(|c| c != '#' && c != '.') {
+                return Err(l.error(format!("Expected {WIDTH} `#` or `.` characters")));
+            }
+            for (h, c) in heights.iter_mut().zip(l.text.bytes()) {
+                *h += (c == b'#') as u8;
+            }
+        }
+        // The solid top or bottom row isn't part of a pin
+        let heights = heights.map(|h| h.saturating_sub(1));
+        match first.text {
+            "#####" => schematics.locks.push(heights),
+            "....." => schematics.keys.push(heights),
+            _ => return Err(first.error("Expected a lock or a key")),
+        }
+    }
+    Ok(schematics)
+}
+
+/// Lock and key pairs whose pins don't overlap in any column
+pub fn part_one(schematics: &Schematics) -> Num {
+    let space = (HEIGHT - 2) as u8;
+    schematics
+        .locks
+        .iter()
+        .flat_map(|lock| schematics.keys.iter().map(move |key| (lock, key)))
+        .filter(|(lock, key)| lock.iter().zip(*key).all(|(l, k)| l + k <= space))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../examples/example.txt");
+
+    common::example_tests!(Day25);
+
+    #[test]
+    fn test_parse() {
+        let schematics = parse(INPUT).unwrap();
+        assert_eq!([[0, 5, 3, 4, 3], [1, 2, 0, 5, 3]], schematics.locks[..]);
+        assert_eq!([5, 0, 2, 1, 3], schematics.keys[0]);
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    common::solution::main::<day25::Day25>(day25::EMBEDDED_INPUT);
+}
@@ -0,0 +1,248 @@
+use common::{
+    generate::{Generated, Rng, SmallRng},
+    ParseError, Solution,
+};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    /// Any memory can be searched for instructions, so this never fails
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> u32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> u32 {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+}
+
+/// `size` instructions, some of them corrupted, amongst other characters and
+/// split over a few lines
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    const NOISE: &[u8] = b"!@#$%^&*()[]{}<>,'+-_?/ mulwhatfromselectwhydo0123456789";
+    let mut input = String::new();
+    for i in 0..size {
+        for _ in 0..rng.gen_range(0..8) {
+            input.push(NOISE[rng.gen_range(0..NOISE.len())] as char);
+        }
+        let (a, b) = (rng.gen_range(1..1000), rng.gen_range(1..1000));
+        let instruction = match rng.gen_range(0..10) {
+            0 => "do()".to_owned(),
+            1 => "don't()".to_owned(),
+            2 => format!("mul({a},{b}]"),
+            3 => format!("mul ( {a},{b})"),
+            4 => format!("mul({a},{}{b})", rng.gen_range(1..10)),
+            _ => format!("mul({a},{b})"),
+        };
+        input.push_str(&instruction);
+        if i % 100 == 99 {
+            input.push('\n');
+        }
+    }
+    input.push('\n');
+    input
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    /// `mul(a,b)`, with 1 to 3 digits in each number
+    Mul(u32, u32),
+    /// `do()`, which enables later `mul`s
+    Do,
+    /// `don't()`, which disables later `mul`s
+    Dont,
+}
+
+/// An instruction and the byte offset it starts at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub offset: usize,
+    pub instruction: Instruction,
+}
+
+/// Finds the instructions amongst the corruption in one pass over the memory.
+/// Anything that isn't exactly an instruction is skipped, a byte at a time,
+/// so an instruction can start inside a broken one.
+#[derive(Debug, Clone)]
+pub struct Lexer<'a> {
+    memory: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(memory: &'a str) -> Self {
+        Self {
+            memory: memory.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    /// The instruction starting at `pos`, and its length
+    fn instruction(&self, pos: usize) -> Option<(Instruction, usize)> {
+        let rest = &self.memory[pos..];
+        if rest.starts_with(b"do()") {
+            return Some((Instruction::Do, 4));
+        }
+        if rest.starts_with(b"don't()") {
+            return Some((Instruction::Dont, 7));
+        }
+        let rest = rest.strip_prefix(b"mul(")?;
+        let (a, rest) = number(rest)?;
+        let rest = rest.strip_prefix(b",")?;
+        let (b, rest) = number(rest)?;
+        let rest = rest.strip_prefix(b")")?;
+        let len = self.memory.len() - pos - rest.len();
+        Some((Instruction::Mul(a, b), len))
+    }
+}
+
+/// A number of 1 to 3 digits at the start of `bytes`, and what follows it
+fn number(bytes: &[u8]) -> Option<(u32, &[u8])> {
+    let digits = bytes
+        .iter()
+        .take(4)
+        .take_while(|b| b.is_ascii_digit())
+        .count();
+    if !(1..=3).contains(&digits) {
+        return None;
+    }
+    let n = bytes[..digits]
+        .iter()
+        .fold(0, |n, d| n * 10 + u32::from(d - b'0'));
+    Some((n, &bytes[digits..]))
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        while self.pos < self.memory.len() {
+            let offset = self.pos;
+            if let Some((instruction, len)) = self.instruction(offset) {
+                self.pos += len;
+                return Some(Token {
+                    offset,
+                    instruction,
+                });
+            }
+            self.pos += 1;
+        }
+        None
+    }
+}
+
+/// The instructions that run, in order. With `conditionals`, `don't()`
+/// disables the `mul`s after it until the next `do()`, and both of those are
+/// kept. Without, they are ignored.
+pub fn execute(memory: &str, conditionals: bool) -> Vec<Token> {
+    let mut enabled = true;
+    Lexer::new(memory)
+        .filter(|token| match token.instruction {
+            Instruction::Mul(..) => enabled,
+            Instruction::Do | Instruction::Dont if !conditionals => false,
+            Instruction::Do => {
+                enabled = true;
+                true
+            }
+            Instruction::Dont => {
+                enabled = false;
+                true
+            }
+        })
+        .collect()
+}
+
+/// Sum of the products of the `mul`s that run
+fn run(memory: &str, conditionals: bool) -> u32 {
+    execute(memory, conditionals)
+        .iter()
+        .map(|token| match token.instruction {
+            Instruction::Mul(a, b) => a * b,
+            _ => 0,
+        })
+        .sum()
+}
+
+pub fn part_one(input: &str) -> u32 {
+    run(input, false)
+}
+
+pub fn part_two(input: &str) -> u32 {
+    run(input, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day3);
+
+    #[test]
+    fn test_non_ascii() {
+        assert_eq!(29, part_one("mul(2,4)\nmul(3,7)é"));
+        assert_eq!(
+            vec![0, 12],
+            execute("mul(1,1)éémul(2,2)", false)
+                .iter()
+                .map(|t| t.offset)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_execute() {
+        let memory = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let token = |offset, instruction| Token {
+            offset,
+            instruction,
+        };
+        assert_eq!(
+            vec![
+                token(1, Instruction::Mul(2, 4)),
+                token(20, Instruction::Dont),
+                token(59, Instruction::Do),
+                token(64, Instruction::Mul(8, 5)),
+            ],
+            execute(memory, true)
+        );
+        assert_eq!(
+            vec![1, 28, 48, 64],
+            execute(memory, false)
+                .iter()
+                .map(|t| t.offset)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_lexer() {
+        let instructions = |memory| {
+            Lexer::new(memory)
+                .map(|t| t.instruction)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec![Instruction::Mul(2, 3)], instructions("mul(mul(2,3)"));
+        assert!(instructions("mul(1234,5)mul(1,2 )mul( 1,2)mul(-1,2)mul(1,2").is_empty());
+        assert_eq!(vec![Instruction::Mul(123, 0)], instructions("mul(123,0)"));
+        // Cutting out what's disabled mustn't join up a broken instruction
+        assert_eq!(0, part_two("mul(1,don't()xdo()34)"));
+    }
+}
@@ -0,0 +1,192 @@
+use common::{
+    generate::{self, Generated, Rng, SmallRng},
+    parse, ParseError, Solution,
+};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input<'a> = &'a str;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::grid(input, "ABCDEFGHIJKLMNOPQRSTUVWXYZ")?;
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> usize {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+}
+
+fn diagonals(input: &str) -> impl Iterator<Item = String> + use<'_> {
+    let width = input.lines().next().unwrap().len();
+    let height = input.len() / width;
+    let diag = (0..width).map(move |s| {
+        input
+            .bytes()
+            .skip(s)
+            .step_by(width + 2)
+            .take(width - s)
+            .collect::<Vec<_>>()
+    });
+    let diag_bottom = (1..height).map(move |s| {
+        input
+            .bytes()
+            .skip((width + 1) * s)
+            .step_by(width + 2)
+            .take(width - s)
+            .collect::<Vec<_>>()
+    });
+    let anti_diag = (0..width).map(move |s| {
+        input
+            .bytes()
+            .skip(s)
+            .step_by(width)
+            .take(s + 1)
+            .collect::<Vec<_>>()
+    });
+    let anti_diag_bottom = (2..height + 1).map(move |s| {
+        input
+            .bytes()
+            .skip((width + 1) * s - 2)
+            .step_by(width)
+            .take((width + 1) - s)
+            .collect::<Vec<_>>()
+    });
+    diag.chain(diag_bottom)
+        .chain(anti_diag)
+        .chain(anti_diag_bottom)
+        .map(|v| String::from_utf8(v).unwrap())
+}
+
+/// A square word search `size` letters wide
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    let size = size.max(1);
+    generate::grid(size, size, |_, _| b"XMAS"[rng.gen_range(0..4)] as char)
+}
+
+pub fn part_one(input: &str) -> usize {
+    // split into lines, then columns, then diagonals
+    // search each one for "XMAS"
+    let width = input.lines().next().unwrap().len();
+    let lines = input.lines().map(str::as_bytes).map(|s| s.to_vec());
+    let columns = (0..width).map(|s| input.bytes().skip(s).step_by(width + 1).collect::<Vec<_>>());
+    let diags = diagonals(input);
+    lines
+        .chain(columns)
+        .map(|l| String::from_utf8(l).unwrap())
+        .chain(diags)
+        .map(|l| {
+            let c = l.matches("XMAS").count() + l.matches("SAMX").count();
+            c
+        })
+        .sum()
+}
+
+fn diagonals_with_indices(input: &str) -> impl Iterator<Item = (String, Vec<usize>)> + use<'_> {
+    let width = input.lines().next().unwrap().len();
+    let height = input.len() / width;
+    let diag = (0..width).map(move |s| {
+        input
+            .bytes()
+            .enumerate()
+            .skip(s)
+            .step_by(width + 2)
+            .take(width - s)
+            .fold((String::new(), Vec::new()), |(mut s, mut v), (i, char)| {
+                s.push(char.into());
+                v.push(i);
+                (s, v)
+            })
+    });
+    let diag_bottom = (1..height).map(move |s| {
+        input
+            .bytes()
+            .enumerate()
+            .skip((width + 1) * s)
+            .step_by(width + 2)
+            .take(width - s)
+            .fold((String::new(), Vec::new()), |(mut s, mut v), (i, char)| {
+                s.push(char.into());
+                v.push(i);
+                (s, v)
+            })
+    });
+    let anti_diag = (0..width).map(move |s| {
+        input
+            .bytes()
+            .enumerate()
+            .skip(s)
+            .step_by(width)
+            .take(s + 1)
+            .fold((String::new(), Vec::new()), |(mut s, mut v), (i, char)| {
+                s.push(char.into());
+                v.push(i);
+                (s, v)
+            })
+    });
+    let anti_diag_bottom = (2..height + 1).map(move |s| {
+        input
+            .bytes()
+            .enumerate()
+            .skip((width + 1) * s - 2)
+            .step_by(width)
+            .take((width + 1) - s)
+            .fold((String::new(), Vec::new()), |(mut s, mut v), (i, char)| {
+                s.push(char.into());
+                v.push(i);
+                (s, v)
+            })
+    });
+    diag.chain(diag_bottom)
+        .chain(anti_diag)
+        .chain(anti_diag_bottom)
+}
+
+pub fn part_two(input: &str) -> usize {
+    // look up "MAS" in diagonals, take index of 'A'
+    // find duplicates
+    let mut a_indices = Vec::new();
+    diagonals_with_indices(input)
+        .map(|(s, v)| {
+            s.match_indices("MAS")
+                .chain(s.match_indices("SAM"))
+                .map(|(i, _)| v[i + 1])
+                .collect::<Vec<_>>()
+        })
+        .for_each(|v| a_indices.extend_from_slice(&v));
+    a_indices
+        .iter()
+        .enumerate()
+        .filter(|(i, oi)| a_indices[i + 1..].contains(oi))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day4);
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Day4::parse("").is_err());
+        assert!(Day4::parse("\n\n\n").is_err());
+    }
+}
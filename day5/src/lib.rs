@@ -0,0 +1,146 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use common::{
+    generate::{Generated, Rng, SliceRandom, SmallRng},
+    parse::lines,
+    ParseError, Solution,
+};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input<'a> = Manual;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> usize {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Manual {
+    rules: HashMap<usize, Vec<usize>>,
+    updates: Vec<Vec<usize>>,
+}
+
+pub fn parse(input: &str) -> Result<Manual, ParseError> {
+    let mut lines = lines(input);
+    let mut rules = HashMap::<usize, Vec<usize>>::new();
+    for l in lines.section() {
+        let (a, b) = l.split_once(l.text, "|")?;
+        rules.entry(l.parse(a)?).or_default().push(l.parse(b)?);
+    }
+    let updates = lines
+        .filter(|l| !l.text.trim().is_empty())
+        .map(|l| l.parse_all(l.text, ","))
+        .collect::<Result<_, _>>()?;
+    Ok(Manual { rules, updates })
+}
+
+fn contain_same_elements<T: PartialEq>(a: &[T], b: &[T]) -> bool {
+    a.iter().any(|n| b.contains(n))
+}
+
+// when we find number n, check all the numbers that must be after and see if
+// we've seen them before. If we have, the line is invalid
+fn in_order(rules: &HashMap<usize, Vec<usize>>, update: &[usize]) -> bool {
+    update.iter().enumerate().all(|(i, n)| {
+        !rules
+            .get(n)
+            .is_some_and(|s| contain_same_elements(s, &update[..i]))
+    })
+}
+
+/// Rules ordering every pair of pages, then `size` updates of an odd number
+/// of pages, some of them out of order
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    let mut pages = (10..100).collect::<Vec<usize>>();
+    pages.shuffle(rng);
+    pages.truncate((size / 4).clamp(5, 49));
+    let mut input = String::new();
+    for (i, a) in pages.iter().enumerate() {
+        for b in &pages[i + 1..] {
+            input.push_str(&format!("{a}|{b}\n"));
+        }
+    }
+    input.push('\n');
+    for _ in 0..size.max(1) {
+        let len = rng.gen_range(0..=(pages.len() - 1) / 2) * 2 + 1;
+        let mut update = pages.clone();
+        update.shuffle(rng);
+        update.truncate(len);
+        if rng.gen_bool(0.5) {
+            update.sort_by_key(|p| pages.iter().position(|q| q == p));
+        }
+        let update = update.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+        input.push_str(&update.join(","));
+        input.push('\n');
+    }
+    input
+}
+
+pub fn part_one(manual: &Manual) -> usize {
+    manual
+        .updates
+        .iter()
+        .filter(|update| in_order(&manual.rules, update))
+        .map(|update| update[update.len() / 2])
+        .sum()
+}
+
+fn compare(map: &HashMap<usize, Vec<usize>>, a: usize, b: usize) -> Ordering {
+    if map.get(&a).is_some_and(|r| r.contains(&b)) {
+        Ordering::Less
+    } else if map.get(&b).is_some_and(|r| r.contains(&a)) {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+pub fn part_two(manual: &Manual) -> usize {
+    manual
+        .updates
+        .iter()
+        .filter(|update| !in_order(&manual.rules, update))
+        .map(|update| {
+            let mut v = update.clone();
+            v.sort_by(|a, b| compare(&manual.rules, *a, *b));
+            v[v.len() / 2]
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day5);
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("47|53\n97-13\n\n75,47\n").unwrap_err();
+        assert_eq!(Some(2), err.line);
+        let err = parse("47|53\n\n75,47\n75,,x\n").unwrap_err();
+        assert_eq!(Some(4), err.line);
+    }
+}
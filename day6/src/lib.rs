@@ -0,0 +1,228 @@
+use std::collections::HashSet;
+
+use common::{
+    generate::{self, Generated, Rng, SmallRng},
+    parse,
+    render::{Frame, Recorder, Rgb},
+    ParseError, Solution,
+};
+use grid::{Dir, Grid, Point};
+use log::{debug, trace};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input<'a> = Lab;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> usize {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+
+    fn animate(input: &Self::Input<'_>, part: u8, frames: &mut Recorder) {
+        match part {
+            1 => _ = part_one_with(input, frames),
+            _ => _ = part_two_with(input, frames),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Lab {
+    walls: Grid<bool>,
+    start: Point,
+    start_dir: Dir,
+}
+
+pub fn parse(input: &str) -> Result<Lab, ParseError> {
+    parse::grid(input, ".#^>v<")?;
+    let mut start = None;
+    let walls = Grid::parse(input, |pt, c| {
+        if let Some(dir) = Dir::from_arrow(c) {
+            start = Some((pt, dir));
+        }
+        c == '#'
+    });
+    debug!("{} x {}", walls.width(), walls.height());
+    debug!("{} barriers", walls.iter().filter(|(_, &w)| w).count());
+    let (start, start_dir) = start.ok_or_else(|| ParseError::new("No guard in the lab"))?;
+    Ok(Lab {
+        walls,
+        start,
+        start_dir,
+    })
+}
+
+/// A lab `size` squares wide that the guard walks out of after a while. No two
+/// obstructions touch diagonally, so the guard never has to turn twice in one
+/// place.
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    let size = size.max(3);
+    loop {
+        let mut walls = Grid::new(size, size, false);
+        for pt in walls.points().collect::<Vec<_>>() {
+            let diagonal = pt
+                .neighbors8()
+                .any(|n| n.x != pt.x && n.y != pt.y && walls.get(n) == Some(&true));
+            walls[pt] = !diagonal && rng.gen_bool(0.08);
+        }
+        let start = Point::new(rng.gen_range(1..size - 1), rng.gen_range(1..size - 1));
+        if walls[start] {
+            continue;
+        }
+        // Keep it if the guard walks a fair way and leaves without looping
+        let (mut pos, mut dir) = (start, Dir::Nor);
+        let mut visited = HashSet::new();
+        while in_interior(&walls, pos) && visited.insert((pos, dir)) {
+            if walls[dir.offset(pos)] {
+                dir = dir.right();
+            } else {
+                pos = dir.offset(pos);
+            }
+        }
+        if !in_interior(&walls, pos) && visited.len() + 6 >= 2 * size {
+            return generate::grid(size, size, |x, y| match Point::new(x, y) {
+                pt if pt == start => '^',
+                pt if walls[pt] => '#',
+                _ => '.',
+            });
+        }
+    }
+}
+
+// Walls don't surround the lab, so stop once the guard reaches the edge
+fn in_interior(walls: &Grid<bool>, pos: Point) -> bool {
+    (1..walls.width() - 1).contains(&pos.x) && (1..walls.height() - 1).contains(&pos.y)
+}
+
+pub fn part_one(lab: &Lab) -> usize {
+    part_one_with(lab, &mut Recorder::off())
+}
+
+fn part_one_with(lab: &Lab, frames: &mut Recorder) -> usize {
+    let walls = &lab.walls;
+    let mut pos = lab.start;
+    let mut dir = lab.start_dir;
+    let mut visited = HashSet::<Point>::new();
+    visited.insert(pos);
+
+    while in_interior(walls, pos) {
+        frames.frame(|| picture(walls, &visited, &HashSet::new(), pos));
+        if walls[dir.offset(pos)] {
+            dir = dir.right();
+        }
+        pos = dir.offset(pos);
+        visited.insert(pos);
+    }
+    frames.last(|| picture(walls, &visited, &HashSet::new(), pos));
+    visited.len()
+}
+
+/// The guard in red, where it has been in blue, and any obstructions that
+/// would trap it in yellow
+fn picture(
+    walls: &Grid<bool>,
+    visited: &HashSet<Point>,
+    targets: &HashSet<Point>,
+    guard: Point,
+) -> Frame {
+    Frame::new(walls.width(), walls.height(), |x, y| {
+        match Point::new(x, y) {
+            pt if pt == guard => Rgb::RED,
+            pt if targets.contains(&pt) => Rgb::YELLOW,
+            pt if walls[pt] => Rgb::GREY,
+            pt if visited.contains(&pt) => Rgb::BLUE,
+            _ => Rgb::BLACK,
+        }
+    })
+}
+
+pub fn part_two(lab: &Lab) -> usize {
+    part_two_with(lab, &mut Recorder::off())
+}
+
+fn part_two_with(lab: &Lab, frames: &mut Recorder) -> usize {
+    let Lab {
+        mut walls,
+        start: mut pos,
+        start_dir: mut dir,
+    } = lab.clone();
+    let mut visited_nod = HashSet::new();
+    let mut visited = HashSet::<(Point, Dir)>::new();
+    visited.insert((pos, dir));
+    visited_nod.insert(pos);
+
+    let mut targets = HashSet::new();
+
+    while in_interior(&walls, pos) {
+        frames.frame(|| picture(&walls, &visited_nod, &targets, pos));
+        // Check adding obstacle (only where guard has not been yet, since obstacle is placed before guard moves)
+        let ahead = dir.offset(pos);
+        if !visited_nod.contains(&ahead) && !walls[ahead] {
+            walls[ahead] = true;
+            if check_path(&walls, visited.clone(), pos, dir.right()) {
+                targets.insert(ahead);
+            }
+            walls[ahead] = false;
+        }
+
+        if walls[ahead] {
+            dir = dir.right();
+        } else {
+            pos = ahead;
+        }
+        visited.insert((pos, dir));
+        visited_nod.insert(pos);
+    }
+    frames.last(|| picture(&walls, &visited_nod, &targets, pos));
+    targets.len()
+}
+
+fn check_path(
+    walls: &Grid<bool>,
+    mut visited: HashSet<(Point, Dir)>,
+    start: Point,
+    start_dir: Dir,
+) -> bool {
+    let mut pos = start;
+    let mut dir = start_dir;
+    visited.insert((pos, dir));
+    trace!("Checking path starting at {pos:?} going {dir:?}");
+    while in_interior(walls, pos) {
+        if walls[dir.offset(pos)] {
+            dir = dir.right();
+        } else {
+            pos = dir.offset(pos);
+        }
+        if !visited.insert((pos, dir)) {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day6);
+}
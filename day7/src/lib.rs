@@ -0,0 +1,119 @@
+use common::{
+    generate::{Generated, Rng, SmallRng},
+    parse::each_line,
+    ParseError, Solution,
+};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input<'a> = Vec<Equation>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> u64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> u64 {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+}
+
+/// Expected total and the numbers to combine
+pub type Equation = (u64, Vec<u64>);
+
+pub fn parse(input: &str) -> Result<Vec<Equation>, ParseError> {
+    each_line(input, |l| {
+        let (exp, nums) = l.split_once(l.text, ":")?;
+        Ok((l.parse(exp)?, l.parse_all(nums, " ")?))
+    })
+}
+
+/// `size` equations of 2 to 7 numbers. About half of them can be made true by
+/// some operators.
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        let nums = (0..rng.gen_range(2..=7))
+            .map(|_| rng.gen_range(1..100))
+            .collect::<Vec<u64>>();
+        let total = if rng.gen_bool(0.5) {
+            nums[1..]
+                .iter()
+                .fold(nums[0], |acc, &n| match rng.gen_range(0..3) {
+                    0 => acc + n,
+                    1 => acc * n,
+                    _ => format!("{acc}{n}").parse().unwrap(),
+                })
+        } else {
+            rng.gen_range(1..1_000_000)
+        };
+        let nums = nums.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        input.push_str(&format!("{total}: {}\n", nums.join(" ")));
+    }
+    input
+}
+
+fn totals(running: u64, nums: &[u64]) -> Vec<u64> {
+    let Some(next) = nums.first() else {
+        return vec![running];
+    };
+    let mut v = totals(running + next, &nums[1..]);
+    v.extend_from_slice(&totals(running * next, &nums[1..]));
+    v
+}
+
+pub fn part_one(equations: &[Equation]) -> u64 {
+    equations
+        .iter()
+        .filter(|(exp, nums)| totals(nums[0], &nums[1..]).contains(exp))
+        .map(|(e, _)| e)
+        .sum()
+}
+
+fn totals2(running: u64, nums: &[u64], max: u64) -> Vec<u64> {
+    if running > max {
+        return Vec::new();
+    }
+    let Some(next) = nums.first() else {
+        return vec![running];
+    };
+    let mut v = totals2(running + next, &nums[1..], max);
+    v.extend_from_slice(&totals2(running * next, &nums[1..], max));
+    v.extend_from_slice(&totals2(
+        format!("{running}{next}").parse().unwrap(),
+        &nums[1..],
+        max,
+    ));
+    v
+}
+
+pub fn part_two(equations: &[Equation]) -> u64 {
+    equations
+        .iter()
+        .filter(|(exp, nums)| totals2(nums[0], &nums[1..], *exp).contains(exp))
+        .map(|(e, _)| e)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day7);
+}
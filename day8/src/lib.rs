@@ -0,0 +1,179 @@
+use std::{
+    collections::{HashMap, HashSet},
+    iter,
+};
+
+use common::{
+    generate::{self, Generated, Rng, SmallRng},
+    parse, ParseError, Solution,
+};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input<'a> = City;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> i32 {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct City {
+    width: i32,
+    height: i32,
+    antennas: HashMap<char, Vec<(i32, i32)>>,
+}
+
+pub fn parse(input: &str) -> Result<City, ParseError> {
+    parse::grid(
+        input,
+        ".0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ",
+    )?;
+    let height = input.lines().count() as i32;
+    let width = input.lines().next().map_or(0, |l| l.len()) as i32;
+    let antennas: HashMap<char, Vec<(i32, i32)>> = input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, l)| {
+            l.chars()
+                .enumerate()
+                .map(move |(x, c)| (x as i32, y as i32, c))
+        })
+        .filter(|(_, _, c)| *c != '.')
+        .fold(HashMap::new(), |mut map, (x, y, c)| {
+            map.entry(c).or_default().push((x, y));
+            map
+        });
+    Ok(City {
+        width,
+        height,
+        antennas,
+    })
+}
+
+/// A map `size` squares wide with a few antennas on each of several
+/// frequencies
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let size = size.max(1);
+    let mut map = vec![vec!['.'; size]; size];
+    for &f in &FREQUENCIES[..(size / 2).clamp(1, FREQUENCIES.len())] {
+        for _ in 0..rng.gen_range(2..=4) {
+            map[rng.gen_range(0..size)][rng.gen_range(0..size)] = f as char;
+        }
+    }
+    generate::grid(size, size, |x, y| map[y][x])
+}
+
+pub fn part_one(city: &City) -> i32 {
+    let &City {
+        width,
+        height,
+        antennas: ref map,
+    } = city;
+    map.values()
+        .flat_map(|pts| {
+            pts.iter()
+                .enumerate()
+                .flat_map(|(i, p1)| pts[i + 1..].iter().flat_map(|p2| antinodes(*p1, *p2)))
+        })
+        .filter(|(x, y)| (0..width).contains(x) && (0..height).contains(y))
+        .fold(HashSet::new(), |mut set, pt| {
+            set.insert(pt);
+            set
+        })
+        .len() as i32
+}
+
+fn antinodes((x1, y1): (i32, i32), (x2, y2): (i32, i32)) -> [(i32, i32); 2] {
+    let dx = x2 - x1;
+    let dy = y2 - y1;
+    [(x1 - dx, y1 - dy), (x2 + dx, y2 + dy)]
+}
+
+pub fn part_two(city: &City) -> i32 {
+    let &City {
+        width,
+        height,
+        antennas: ref map,
+    } = city;
+    map.values()
+        .flat_map(|pts| {
+            pts.iter().enumerate().flat_map(|(i, p1)| {
+                pts[i + 1..]
+                    .iter()
+                    .flat_map(|p2| antinodes2(*p1, *p2, width, height))
+            })
+        })
+        .fold(HashSet::new(), |mut set, pt| {
+            set.insert(pt);
+            set
+        })
+        .len() as i32
+}
+
+fn antinodes2(
+    (mut x1, mut y1): (i32, i32),
+    (mut x2, mut y2): (i32, i32),
+    width: i32,
+    height: i32,
+) -> impl Iterator<Item = (i32, i32)> {
+    let dx = x2 - x1;
+    let dy = y2 - y1;
+
+    iter::once((x1, y1))
+        .chain(iter::once((x2, y2)))
+        .chain(
+            iter::from_fn(move || {
+                x1 -= dx;
+                y1 -= dy;
+                Some((x1, y1))
+            })
+            .take_while(move |(x, y)| (0..width).contains(x) && (0..height).contains(y)),
+        )
+        .chain(
+            iter::from_fn(move || {
+                x2 += dx;
+                y2 += dy;
+                Some((x2, y2))
+            })
+            .take_while(move |(x, y)| (0..width).contains(x) && (0..height).contains(y)),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day8);
+
+    #[test]
+    fn test_antinodes() {
+        let nodes = antinodes((0, 0), (1, 1));
+        assert_eq!([(-1, -1), (2, 2)], nodes);
+
+        let nodes = antinodes((0, 0), (1, 2));
+        assert_eq!([(-1, -2), (2, 4)], nodes);
+    }
+}
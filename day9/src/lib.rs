@@ -0,0 +1,219 @@
+use std::iter;
+
+use common::{
+    generate::{Generated, Rng, SmallRng},
+    parse::lines,
+    render::{Frame, Recorder, Rgb},
+    ParseError, Solution,
+};
+use log::trace;
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+type Num = usize;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input<'a> = Vec<Option<Num>>;
+    type PartOne = Num;
+    type PartTwo = Num;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Num {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Num {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+
+    fn animate(input: &Self::Input<'_>, part: u8, frames: &mut Recorder) {
+        match part {
+            1 => _ = part_one_with(input, frames),
+            _ => _ = part_two_with(input, frames),
+        }
+    }
+}
+
+/// File id of each block on the disk, `None` for free space
+pub fn parse(input: &str) -> Result<Vec<Option<Num>>, ParseError> {
+    let mut lines = lines(input.trim());
+    let disk = lines.expect("disk map")?;
+    if let Some(l) = lines.next() {
+        return Err(l.error("Expected the disk map on a single line"));
+    }
+    if let Some(c) = disk.text.chars().find(|c| !c.is_ascii_digit()) {
+        return Err(disk.error(format!("Unexpected `{c}`")));
+    }
+    let blocks = disk
+        .text
+        .bytes()
+        .enumerate()
+        .flat_map(|(i, b)| {
+            let val = if i % 2 == 0 {
+                Some((i / 2) as Num)
+            } else {
+                None
+            };
+            iter::repeat_n(val, (b - b'0') as Num)
+        })
+        .collect::<Vec<_>>();
+    if blocks.iter().all(Option::is_none) {
+        return Err(disk.error("Expected at least one file block"));
+    }
+    Ok(blocks)
+}
+
+/// A disk map of `size` files with free space between them
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    let mut disk = String::new();
+    for i in 0..size.max(1) {
+        if i > 0 {
+            disk.push(char::from(b'0' + rng.gen_range(0..=9)));
+        }
+        disk.push(char::from(b'0' + rng.gen_range(1..=9)));
+    }
+    disk.push('\n');
+    disk
+}
+
+pub fn part_one(blocks: &[Option<Num>]) -> Num {
+    part_one_with(blocks, &mut Recorder::off())
+}
+
+fn part_one_with(blocks: &[Option<Num>], frames: &mut Recorder) -> Num {
+    let mut blocks = blocks.to_vec();
+
+    let (mut start, mut end) = (0, blocks.len() - 1);
+    while start <= end {
+        if blocks[start].is_some() {
+            start += 1;
+            continue;
+        }
+        if blocks[end].is_none() {
+            end -= 1;
+            continue;
+        }
+        blocks.swap(start, end);
+        frames.frame(|| picture(&blocks));
+    }
+    frames.last(|| picture(&blocks));
+    blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(i, b)| b.map(|id| id * i))
+        .sum()
+}
+
+pub fn part_two(blocks: &[Option<Num>]) -> Num {
+    part_two_with(blocks, &mut Recorder::off())
+}
+
+fn part_two_with(blocks: &[Option<Num>], frames: &mut Recorder) -> Num {
+    let mut blocks = blocks.to_vec();
+    let mut prev = Num::MAX;
+    // index, len
+    let mut spaces: Vec<(Num, Num)> =
+        blocks
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| b.is_none())
+            .fold(Vec::new(), |mut v, (i, _)| {
+                if i.checked_sub(prev) == Some(1) {
+                    v.last_mut().unwrap().1 += 1;
+                } else {
+                    v.push((i, 1));
+                }
+                prev = i;
+                v
+            });
+    // index, len
+    let mut prev = Num::MAX;
+    let mut id = Num::MAX;
+    let filesizes: Vec<(Num, Num)> = blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(i, b)| b.map(|b| (i, b)))
+        .fold(Vec::new(), |mut v, (i, b)| {
+            if i.checked_sub(prev) == Some(1) && b == id {
+                v.last_mut().unwrap().1 += 1;
+            } else {
+                v.push((i, 1));
+                id = b;
+            }
+            prev = i;
+            v
+        });
+
+    for (fstart, flen) in filesizes.into_iter().rev() {
+        let Some((sstart, slen)) = spaces.iter_mut().find(|(_, slen)| *slen >= flen) else {
+            continue;
+        };
+        if fstart < *sstart {
+            continue;
+        }
+        // swap fstart..fstart+flen with sstart..start+flen
+        // update slen -= flen AND sstart += flen
+        // slen = 0 not a problem bc it'll never be used
+        let (space, file) = blocks.split_at_mut(fstart);
+        file[..flen].swap_with_slice(&mut space[*sstart..*sstart + flen]);
+        *slen -= flen;
+        *sstart += flen;
+        trace!("Map: {}", draw(&blocks));
+        frames.frame(|| picture(&blocks));
+    }
+    frames.last(|| picture(&blocks));
+
+    blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(i, b)| b.map(|id| id * i))
+        .sum()
+}
+
+/// The disk wrapped into a square, with a colour for each file and black for
+/// free space
+fn picture(blocks: &[Option<Num>]) -> Frame {
+    let width = blocks.len().isqrt().max(1);
+    let height = blocks.len().div_ceil(width);
+    Frame::new(width, height, |x, y| match blocks.get(y * width + x) {
+        Some(Some(id)) => Rgb::distinct(*id),
+        _ => Rgb::BLACK,
+    })
+}
+
+/// The disk as the puzzle draws it, with `.` for free space
+fn draw(blocks: &[Option<Num>]) -> String {
+    blocks
+        .iter()
+        .map(|b| b.map_or(".".to_owned(), |i| i.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day9);
+
+    #[test]
+    fn test_parse_error() {
+        assert!(parse("12a").is_err());
+        assert!(parse("0").is_err());
+        assert!(parse("01").is_err());
+        assert!(parse("0\n1").is_err());
+        assert_eq!(vec![None, Some(1)], parse("011").unwrap());
+    }
+}
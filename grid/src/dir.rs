@@ -0,0 +1,91 @@
+use crate::Point;
+
+/// Cardinal direction on a grid where y increases going south
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    Nor,
+    East,
+    Sou,
+    West,
+}
+
+impl Dir {
+    /// Clockwise, starting from north
+    pub const ALL: [Dir; 4] = [Dir::Nor, Dir::East, Dir::Sou, Dir::West];
+
+    /// Direction for one of `^`, `>`, `v` or `<`
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Dir::Nor),
+            '>' => Some(Dir::East),
+            'v' => Some(Dir::Sou),
+            '<' => Some(Dir::West),
+            _ => None,
+        }
+    }
+
+    pub fn left(self) -> Self {
+        match self {
+            Dir::Nor => Dir::West,
+            Dir::East => Dir::Nor,
+            Dir::Sou => Dir::East,
+            Dir::West => Dir::Sou,
+        }
+    }
+
+    pub fn right(self) -> Self {
+        match self {
+            Dir::Nor => Dir::East,
+            Dir::East => Dir::Sou,
+            Dir::Sou => Dir::West,
+            Dir::West => Dir::Nor,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Dir::Nor => Dir::Sou,
+            Dir::East => Dir::West,
+            Dir::Sou => Dir::Nor,
+            Dir::West => Dir::East,
+        }
+    }
+
+    /// Moves `pt` one step. Only for maps where walls surround the board, since
+    /// this overflows when stepping off the top or left edge.
+    pub fn offset(self, pt: Point) -> Point {
+        match self {
+            Dir::Nor => Point::new(pt.x, pt.y - 1),
+            Dir::East => Point::new(pt.x + 1, pt.y),
+            Dir::Sou => Point::new(pt.x, pt.y + 1),
+            Dir::West => Point::new(pt.x - 1, pt.y),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotation() {
+        for d in Dir::ALL {
+            assert_eq!(d, d.left().right());
+            assert_eq!(d.reverse(), d.left().left());
+            assert_eq!(d, d.right().right().right().right());
+        }
+        assert_eq!(
+            Dir::ALL.map(Dir::right),
+            [Dir::East, Dir::Sou, Dir::West, Dir::Nor]
+        );
+    }
+
+    #[test]
+    fn test_from_arrow() {
+        assert_eq!(
+            "^>v<".chars().map(Dir::from_arrow).collect::<Vec<_>>(),
+            Dir::ALL.map(Some)
+        );
+        assert_eq!(None, Dir::from_arrow('.'));
+    }
+}
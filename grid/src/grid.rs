@@ -0,0 +1,161 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{Dir, Point};
+
+/// Dense, row-major 2D grid
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from a character map, one row per line. Every line must
+    /// be the same length.
+    ///
+    /// # Panics
+    ///
+    /// If a line is wider or narrower than the first. Check untrusted input
+    /// with `common::parse::grid` first, which reports that as an error.
+    pub fn parse(input: &str, mut cell: impl FnMut(Point, char) -> T) -> Self {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+        for (y, l) in input.lines().enumerate() {
+            let before = cells.len();
+            cells.extend(
+                l.chars()
+                    .enumerate()
+                    .map(|(x, c)| cell(Point::new(x, y), c)),
+            );
+            let len = cells.len() - before;
+            if y == 0 {
+                width = len;
+            }
+            assert_eq!(width, len, "Line {} has a different width", y + 1);
+            height += 1;
+        }
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pt: Point) -> bool {
+        pt.x < self.width && pt.y < self.height
+    }
+
+    pub fn get(&self, pt: Point) -> Option<&T> {
+        self.contains(pt)
+            .then(|| &self.cells[pt.y * self.width + pt.x])
+    }
+
+    pub fn get_mut(&mut self, pt: Point) -> Option<&mut T> {
+        self.contains(pt)
+            .then(|| &mut self.cells[pt.y * self.width + pt.x])
+    }
+
+    /// Moves `pt` one step in `dir`, if that stays on the grid
+    pub fn step(&self, pt: Point, dir: Dir) -> Option<Point> {
+        pt.checked_move(dir).filter(|&pt| self.contains(pt))
+    }
+
+    /// Every point in the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// First point, row by row, whose cell matches `pred`
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, c)| pred(c)).map(|(pt, _)| pt)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pt: Point) -> &T {
+        self.get(pt).unwrap_or_else(|| {
+            panic!(
+                "{pt:?} is outside of the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pt: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pt)
+            .unwrap_or_else(|| panic!("{pt:?} is outside of the {width}x{height} grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "#.#
+.S.
+#.E";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(MAP, |_, c| c);
+        assert_eq!((3, 3), (grid.width(), grid.height()));
+        assert_eq!('S', grid[Point::new(1, 1)]);
+        assert_eq!(Some(&'E'), grid.get(Point::new(2, 2)));
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+        assert_eq!(Some(Point::new(2, 2)), grid.position(|&c| c == 'E'));
+        assert_eq!(['.', 'S', '.'], grid.row(1));
+    }
+
+    #[test]
+    fn test_step() {
+        let grid = Grid::parse(MAP, |_, c| c == '#');
+        assert_eq!(None, grid.step(Point::new(0, 0), Dir::Nor));
+        assert_eq!(None, grid.step(Point::new(2, 1), Dir::East));
+        assert_eq!(
+            Some(Point::new(1, 2)),
+            grid.step(Point::new(1, 1), Dir::Sou)
+        );
+        assert_eq!(3, grid.iter().filter(|(_, &wall)| wall).count());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ragged() {
+        Grid::parse("..\n.", |_, c| c);
+    }
+}
@@ -0,0 +1,7 @@
+mod dir;
+mod grid;
+mod point;
+
+pub use dir::Dir;
+pub use grid::Grid;
+pub use point::Point;
@@ -0,0 +1,180 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    sync::{LazyLock, Mutex},
+};
+
+use crate::Dir;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn checked_move(self, dir: Dir) -> Option<Self> {
+        match dir {
+            Dir::Nor => self.y.checked_sub(1).map(|y| Self::new(self.x, y)),
+            Dir::East => self.x.checked_add(1).map(|x| Self::new(x, self.y)),
+            Dir::Sou => self.y.checked_add(1).map(|y| Self::new(self.x, y)),
+            Dir::West => self.x.checked_sub(1).map(|x| Self::new(x, self.y)),
+        }
+    }
+
+    /// Manhattan distance between two points
+    pub const fn dist(&self, other: &Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The (up to) four orthogonally adjacent points
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |d| self.checked_move(d))
+    }
+
+    /// The (up to) eight adjacent points, including diagonals
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        Dir::ALL.into_iter().flat_map(move |d| {
+            let pt = self.checked_move(d);
+            // Each direction plus the diagonal clockwise of it
+            pt.into_iter()
+                .chain(pt.and_then(|pt| pt.checked_move(d.right())))
+        })
+    }
+
+    /// Finds all points with a manhattan distance of at most `dist`, nearest
+    /// first
+    pub fn within(self, dist: usize) -> impl Iterator<Item = Self> {
+        if dist == 0 {
+            panic!("within only works with distances greater than 0");
+        }
+        Self::neighbor_dirs(dist)
+            .into_iter()
+            .filter_map(move |dirs| {
+                let mut pt = self;
+                for d in dirs {
+                    pt = pt.checked_move(d)?;
+                }
+                Some(pt)
+            })
+    }
+
+    fn neighbor_dirs(dist: usize) -> Vec<Vec<Dir>> {
+        static MAP: LazyLock<Mutex<HashMap<usize, Vec<Vec<Dir>>>>> =
+            LazyLock::new(|| Mutex::new(HashMap::new()));
+        MAP.lock()
+            .unwrap()
+            .entry(dist)
+            .or_insert_with(|| {
+                let mut directions = vec![
+                    vec![Dir::Nor],
+                    vec![Dir::West],
+                    vec![Dir::Sou],
+                    vec![Dir::East],
+                ];
+                for d in 1..dist {
+                    for i in 0..directions.len() {
+                        if directions[i].len() != d {
+                            continue;
+                        }
+
+                        let last_dir = *directions[i].last().unwrap();
+                        if directions[i].iter().all(|&d| d == last_dir) {
+                            let mut nv = directions[i].clone();
+                            nv.push(last_dir.left());
+                            directions.push(nv.clone())
+                        }
+                        let mut nv = directions[i].clone();
+                        nv.push(last_dir);
+                        directions.push(nv.clone())
+                    }
+                }
+                directions
+            })
+            .clone()
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self { x, y }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighbors() {
+        let pt = Point::new(5, 5);
+        assert_eq!(pt.neighbors().count(), 4);
+        assert_eq!(Point::new(0, 0).neighbors().count(), 2);
+
+        let all = pt.neighbors8().collect::<Vec<_>>();
+        assert_eq!(all.len(), 8);
+        assert!(all
+            .iter()
+            .all(|n| n.x.abs_diff(5) <= 1 && n.y.abs_diff(5) <= 1));
+        assert!(!all.contains(&pt));
+        assert_eq!(Point::new(0, 0).neighbors8().count(), 3);
+    }
+
+    #[test]
+    fn test_point_within() {
+        let pt = Point::new(5, 5);
+        let neighbors = pt.within(1).collect::<Vec<_>>();
+        assert_eq!(neighbors.len(), 4);
+        assert_eq!(
+            neighbors,
+            [
+                Point { x: 5, y: 4 },
+                Point { x: 4, y: 5 },
+                Point { x: 5, y: 6 },
+                Point { x: 6, y: 5 }
+            ]
+        );
+
+        let neighbors2 = pt.within(2).collect::<Vec<_>>();
+        assert!(neighbors2.starts_with(&neighbors));
+        assert_eq!(neighbors2.len(), 12);
+        assert_eq!(
+            neighbors2,
+            [
+                Point { x: 5, y: 4 },
+                Point { x: 4, y: 5 },
+                Point { x: 5, y: 6 },
+                Point { x: 6, y: 5 },
+                Point { x: 4, y: 4 },
+                Point { x: 5, y: 3 },
+                Point { x: 4, y: 6 },
+                Point { x: 3, y: 5 },
+                Point { x: 6, y: 6 },
+                Point { x: 5, y: 7 },
+                Point { x: 6, y: 4 },
+                Point { x: 7, y: 5 }
+            ]
+        );
+
+        let neighbors3 = pt.within(3).collect::<Vec<_>>();
+        assert_eq!(neighbors3.len(), 24);
+        assert!(neighbors3.into_iter().all(|other| pt.dist(&other) <= 3));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("6,1", Point::new(6, 1).to_string());
+    }
+}
@@ -0,0 +1,60 @@
+use common::{
+    generate::{Generated, SmallRng},
+    ParseError, Solution,
+};
+
+#[cfg(feature = "embedded-input")]
+pub const EMBEDDED_INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embedded-input"))]
+pub const EMBEDDED_INPUT: Option<&str> = None;
+
+type Num = usize;
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    const DAY: u8 = {{day}};
+    type Input<'a> = &'a str;
+    type PartOne = Num;
+    type PartTwo = Num;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Num {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Num {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut SmallRng, size: usize) -> Generated {
+        generate(rng, size).into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<&str, ParseError> {
+    Ok(input)
+}
+
+/// A random input with `size` lines
+pub fn generate(rng: &mut SmallRng, size: usize) -> String {
+    todo!()
+}
+
+pub fn part_one(input: &str) -> Num {
+    todo!()
+}
+
+pub fn part_two(input: &str) -> Num {
+    todo!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day{{day}});
+}